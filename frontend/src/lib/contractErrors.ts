@@ -33,6 +33,41 @@ const ERROR_MESSAGES: Record<number, string> = {
   29: 'Arithmetic overflow detected.',
   30: 'You are not the creator of this resource.',
   31: 'Insufficient incentive budget.',
+  32: 'Participant has deactivated their account.',
+  33: 'Name cannot be empty.',
+  34: 'Only recyclers can perform this action.',
+  35: 'Material batch not found.',
+  36: 'This batch already changed hands in this ledger. Please try again shortly.',
+  37: 'The contract is paused. Please try again later.',
+  38: 'You can only rate participants you have exchanged a batch with.',
+  39: 'You have already rated this participant today.',
+  40: 'Batch status can only move forward.',
+  41: 'This batch has been recycled and can no longer change hands.',
+  42: 'A participant must keep at least one role.',
+  43: 'Split weights must add up to the batch weight.',
+  44: 'Only batches of the same material can be merged.',
+  45: 'You do not own this batch.',
+  46: 'Select at least two different batches to merge.',
+  47: 'This batch already has a pending payment offer.',
+  48: 'This batch has no pending payment offer.',
+  49: 'Not enough reward points.',
+  50: 'The reward pool cannot cover this redemption right now.',
+  51: 'Too many registrations right now. Please try again shortly.',
+  52: 'No role request is awaiting approval.',
+  53: 'This batch has no open dispute.',
+  54: 'This batch is under dispute and cannot be changed.',
+  55: 'Only past or present holders can dispute this batch.',
+  56: 'This batch is already under dispute.',
+  57: 'Location verification is not configured.',
+  58: 'Location proof has expired. Please request a new one.',
+  59: 'This batch has expired, or its expiry is not in the future.',
+  60: 'Too many entries in one request.',
+  61: 'No price is set for this material yet.',
+  62: 'That name is already taken.',
+  63: 'Material can only move downstream: recycler to collector to manufacturer.',
+  64: 'Requested precision is out of range.',
+  65: 'Contact handle must be 1 to 128 characters.',
+  66: 'The start of the time window must not be after its end.',
 }
 
 export function getErrorMessage(error: unknown): string {
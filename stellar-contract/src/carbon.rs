@@ -0,0 +1,97 @@
+//! CO2-saved estimates for recycled material.
+//!
+//! Factors are milligrams of CO2 avoided per gram of material recycled
+//! instead of produced from virgin feedstock, rounded from published
+//! life-cycle averages. They are estimates for reporting, not certified
+//! offsets.
+
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::storage::{self, DataKey};
+use crate::{BatchStatus, MaterialBatch, MaterialKind};
+
+/// Milligrams of CO2 saved per gram of `kind` recycled.
+pub fn mg_co2_per_gram(kind: MaterialKind) -> u64 {
+    match kind {
+        MaterialKind::Plastic => 1_500,
+        MaterialKind::Glass => 300,
+        // Dominated by aluminium, which saves ~95% of primary smelting energy.
+        MaterialKind::Metal => 9_000,
+        MaterialKind::Paper => 1_000,
+        MaterialKind::EWaste => 2_000,
+    }
+}
+
+/// Grams of CO2 saved by recycling `weight_grams` of `kind`.
+pub fn grams_saved(kind: MaterialKind, weight_grams: u64) -> u64 {
+    let mg = weight_grams as u128 * mg_co2_per_gram(kind) as u128;
+    (mg / 1_000).min(u64::MAX as u128) as u64
+}
+
+/// Grams of CO2 saved by `batch`, or 0 until it reaches `Recycled`.
+pub fn batch_saved(batch: &MaterialBatch) -> u64 {
+    if batch.status == BatchStatus::Recycled {
+        grams_saved(batch.kind, batch.weight_grams)
+    } else {
+        0
+    }
+}
+
+/// Grams of CO2 credited to `address` for batches it created.
+pub fn participant_total(env: &Env, address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarbonTotal(address.clone()))
+        .unwrap_or(0)
+}
+
+/// Credit a batch that has just been recycled to whoever created it.
+///
+/// A batch merged from several creators' material is credited to each in
+/// proportion to the weight they supplied; rounding leftovers go to the
+/// last creator.
+pub fn record_recycled(env: &Env, batch: &MaterialBatch) -> Result<(), Error> {
+    let origins = storage::batch_origins(env, batch);
+    let supplied: u128 = origins.iter().map(|o| o.weight_grams as u128).sum();
+    let saved = batch_saved(batch);
+    let mut credited = 0u64;
+    for (i, origin) in origins.iter().enumerate() {
+        let share = if i as u32 + 1 == origins.len() {
+            saved - credited
+        } else {
+            (saved as u128 * origin.weight_grams as u128 / supplied) as u64
+        };
+        credited += share;
+        let total = participant_total(env, &origin.creator)
+            .checked_add(share)
+            .ok_or(Error::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::CarbonTotal(origin.creator), &total);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metal_saves_more_than_glass() {
+        assert!(mg_co2_per_gram(MaterialKind::Metal) > mg_co2_per_gram(MaterialKind::Glass));
+    }
+
+    #[test]
+    fn test_grams_saved_scales_with_weight() {
+        assert_eq!(grams_saved(MaterialKind::Glass, 10_000), 3_000);
+        assert_eq!(grams_saved(MaterialKind::Metal, 1_000), 9_000);
+        assert_eq!(grams_saved(MaterialKind::Plastic, 1), 1);
+        assert_eq!(grams_saved(MaterialKind::Glass, 0), 0);
+    }
+
+    #[test]
+    fn test_grams_saved_saturates() {
+        assert_eq!(grams_saved(MaterialKind::Metal, u64::MAX), u64::MAX);
+    }
+}
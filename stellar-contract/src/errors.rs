@@ -6,48 +6,97 @@ use soroban_sdk::contracterror;
 /// Frontend clients should map the numeric `u32` code (shown in parentheses)
 /// to a user-facing message.
 ///
+/// # Stability
+/// The discriminants are part of the public ABI: off-chain indexers and
+/// clients hardcode them when decoding `Error(Contract, #N)` results. A code
+/// must never be renumbered or reused once released — new variants are only
+/// ever appended with the next free number, and retired variants keep their
+/// slot.
+///
+/// Soroban caps a contract error enum at 50 declared cases, not at code 50.
+/// Retired codes are therefore left out of the enum but stay listed below so
+/// their numbers are never handed out again; a new variant always takes the
+/// next number after the highest one in the table.
+///
 /// | Code | Variant | Meaning |
 /// |------|---------|---------|
 /// | 1 | `AlreadyInitialized` | Admin already set |
-/// | 2 | `Unauthorized` | Caller is not the admin |
+/// | 2 | `Unauthorized` | Caller is not the admin (or price admin) |
 /// | 3 | `NotRegistered` | Address is not a registered participant |
 /// | 4 | `AlreadyRegistered` | Address is already registered |
-/// | 5 | `NotManufacturer` | Caller's role is not Manufacturer |
-/// | 6 | `NotWasteOwner` | Caller does not own the waste item |
+/// | 5 | `NotManufacturer` | Retired; never returned |
+/// | 6 | `NotWasteOwner` | Retired; never returned |
 /// | 7 | `WasteNotFound` | No waste record exists for the given ID |
-/// | 8 | `MaterialNotFound` | No material record exists for the given ID |
-/// | 9 | `IncentiveNotFound` | No incentive record exists for the given ID |
+/// | 8 | `MaterialNotFound` | Retired; never returned |
+/// | 9 | `IncentiveNotFound` | Retired; never returned |
 /// | 10 | `ParticipantNotFound` | No participant record exists for the given address |
 /// | 11 | `InvalidAmount` | Amount is zero or negative |
 /// | 12 | `InvalidWeight` | Weight is zero |
 /// | 13 | `InvalidCoordinates` | Latitude or longitude is out of range |
-/// | 14 | `InvalidPercentage` | Percentages sum exceeds 100 |
-/// | 15 | `InsufficientBalance` | Donor's token balance is too low |
-/// | 16 | `CharityNotSet` | Charity contract address has not been configured |
+/// | 14 | `InvalidPercentage` | Retired; never returned |
+/// | 15 | `InsufficientBalance` | Retired; never returned |
+/// | 16 | `CharityNotSet` | Retired; never returned |
 /// | 17 | `TokenAddressNotSet` | Token contract address has not been configured |
 /// | 18 | `WasteDeactivated` | Operation rejected because the waste is deactivated |
-/// | 19 | `WasteAlreadyDeactivated` | Waste is already in the deactivated state |
-/// | 20 | `WasteAlreadyConfirmed` | Waste has already been confirmed |
-/// | 21 | `WasteNotConfirmed` | Waste has not been confirmed yet |
-/// | 22 | `SelfConfirmation` | Owner cannot confirm their own waste |
-/// | 23 | `IncentiveInactive` | Incentive is not active |
-/// | 24 | `MaterialNotVerified` | Material must be verified before claiming |
-/// | 25 | `WasteTypeMismatch` | Material waste type does not match incentive |
-/// | 26 | `NoRewardAvailable` | Calculated reward is zero (budget exhausted or weight too low) |
+/// | 19 | `WasteAlreadyDeactivated` | Retired; never returned |
+/// | 20 | `WasteAlreadyConfirmed` | Retired; never returned |
+/// | 21 | `WasteNotConfirmed` | Retired; never returned |
+/// | 22 | `SelfConfirmation` | Retired; never returned |
+/// | 23 | `IncentiveInactive` | Retired; never returned |
+/// | 24 | `MaterialNotVerified` | Retired; never returned |
+/// | 25 | `WasteTypeMismatch` | Retired; never returned |
+/// | 26 | `NoRewardAvailable` | Retired; never returned |
 /// | 27 | `InvalidTransferRoute` | Role combination is not a permitted transfer route |
 /// | 28 | `SameAddress` | Two addresses that must differ are equal |
 /// | 29 | `Overflow` | Arithmetic overflow detected |
-/// | 30 | `NotCreator` | Caller is not the original creator of the resource |
+/// | 30 | `NotCreator` | Retired; never returned |
+/// | 31 | `InsufficientBudget` | Incentive budget cannot cover the reward |
+/// | 32 | `Inactive` | Participant has deactivated themselves |
+/// | 33 | `InvalidName` | Participant name is empty |
+/// | 34 | `NotRecycler` | Caller's role is not `Recycler` |
+/// | 35 | `BatchNotFound` | No material batch with the given ID |
+/// | 36 | `TransferTooSoon` | Retired; same-ledger hand-overs are allowed again |
+/// | 37 | `Paused` | Contract is paused by the admin |
+/// | 38 | `NotCounterparty` | No batch has passed between rater and target |
+/// | 39 | `AlreadyRated` | Rater already rated this target today |
+/// | 40 | `InvalidTransition` | Batch status can only move forward |
+/// | 41 | `BatchRecycled` | Recycled batches cannot be transferred |
+/// | 42 | `LastRole` | A participant must keep at least one role |
+/// | 43 | `WeightMismatch` | Split weights do not sum to the batch weight |
+/// | 44 | `KindMismatch` | Batches to merge are of different kinds |
+/// | 45 | `NotBatchOwner` | Caller does not own the material batch |
+/// | 46 | `TooFewBatches` | Merge needs at least two distinct batches |
+/// | 47 | `EscrowExists` | Batch already has a pending payment offer |
+/// | 48 | `EscrowNotFound` | Batch has no pending payment offer |
+/// | 49 | `InsufficientPoints` | Point balance is too low for the redemption |
+/// | 50 | `InsufficientRewardPool` | Contract holds too few reward tokens |
+/// | 51 | `RateLimited` | Registration limit for this ledger reached |
+/// | 52 | `NoPendingRole` | Address has no role request awaiting approval |
+/// | 53 | `NotDisputed` | Batch has no open dispute |
+/// | 54 | `BatchDisputed` | Batch is frozen by an open dispute |
+/// | 55 | `NotCustodian` | Caller never held the batch |
+/// | 56 | `AlreadyDisputed` | Batch already has an open dispute |
+/// | 57 | `LocationOracleNotSet` | No location oracle key has been configured |
+/// | 58 | `StaleLocationProof` | Location proof timestamp is in the future or too old |
+/// | 59 | `BatchExpired` | Batch has passed its expiry time |
+/// | 60 | `TooManyEntries` | Too many entries in one bulk call |
+/// | 61 | `NoPrice` | No price has been published for the material kind |
+/// | 62 | `NameTaken` | Display name is held by another participant |
+/// | 63 | `IllegalFlow` | Transfer goes against the canonical role flow |
+/// | 64 | `InvalidPrecision` | Requested precision is out of range |
+/// | 65 | `InvalidHandle` | Contact handle is empty or longer than 128 bytes |
+/// | 66 | `InvalidWindow` | Time window starts after it ends |
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Error {
     /// (1) The contract admin has already been initialised.
-    /// Returned by: `initialize_admin`
+    /// Returned by: `initialize`
     AlreadyInitialized = 1,
 
-    /// (2) The caller is not the contract administrator.
-    /// Returned by: any admin-only function
+    /// (2) The caller is not the contract administrator, or for `set_price`
+    /// not the designated price admin.
+    /// Returned by: any admin-only function, `set_price`
     Unauthorized = 2,
 
     /// (3) The address is not a registered participant, or `is_registered` is false.
@@ -58,117 +107,200 @@ pub enum Error {
     /// Returned by: `register_participant`
     AlreadyRegistered = 4,
 
-    /// (5) The caller's role is not `Manufacturer`.
-    /// Returned by: `create_incentive`
-    NotManufacturer = 5,
-
-    /// (6) The caller does not own the specified waste item.
-    /// Returned by: `transfer_waste_v2`, `reset_waste_confirmation`, `deactivate_waste`
-    NotWasteOwner = 6,
-
     /// (7) No waste record exists for the given ID (v2 storage).
     /// Returned by: `transfer_waste_v2`, `confirm_waste_details`, `reset_waste_confirmation`,
     ///              `deactivate_waste`
     WasteNotFound = 7,
 
-    /// (8) No material record exists for the given ID (v1 storage).
-    /// Returned by: `verify_material`, `transfer_waste`, `claim_incentive_reward`
-    MaterialNotFound = 8,
-
-    /// (9) No incentive record exists for the given ID.
-    /// Returned by: `update_incentive`, `update_incentive_status`, `calculate_incentive_reward`,
-    ///              `claim_incentive_reward`, `deactivate_incentive`
-    IncentiveNotFound = 9,
-
     /// (10) No participant record exists for the given address.
     /// Returned by: `update_role`, `deregister_participant`, `update_location`,
-    ///              `verify_material`, `donate_to_charity`
+    ///              `verify_material`, `donate_to_charity`, `bump_participant_ttl`,
+    ///              `migrate_participant`, `request_role`, `approve_role`,
+    ///              `update_participant_location`
     ParticipantNotFound = 10,
 
     /// (11) A monetary or token amount is zero or negative.
-    /// Returned by: `donate_to_charity`, `reward_tokens`
+    /// Returned by: `donate_to_charity`, `reward_tokens`, `set_points_divisor`, `set_ttl`,
+    ///              `offer_payment`, `set_points_per_token`, `redeem_points`,
+    ///              `set_registration_limit`
     InvalidAmount = 11,
 
     /// (12) A waste weight value is zero.
-    /// Returned by: `recycle_waste`
+    /// Returned by: `recycle_waste`, `create_material_batch`, `split_batch`
     InvalidWeight = 12,
 
     /// (13) Latitude is outside [-90°, +90°] or longitude outside [-180°, +180°]
     /// (values in microdegrees, e.g. ±90_000_000).
-    /// Returned by: `register_participant`
+    /// Returned by: `register_participant`, `update_location`,
+    ///              `update_participant_location`
     InvalidCoordinates = 13,
 
-    /// (14) `collector_percentage + owner_percentage` exceeds 100.
-    /// Returned by: `set_percentages`, `set_collector_percentage`, `set_owner_percentage`
-    InvalidPercentage = 14,
-
-    /// (15) The donor's `total_tokens_earned` is less than the requested donation amount.
-    /// Returned by: `donate_to_charity`
-    InsufficientBalance = 15,
-
-    /// (16) No charity contract address has been set via `set_charity_contract`.
-    /// Returned by: `donate_to_charity`
-    CharityNotSet = 16,
-
     /// (17) No token contract address has been set via `set_token_address`.
-    /// Returned by: `reward_tokens`
+    /// Returned by: `reward_tokens`, `redeem_points`
     TokenAddressNotSet = 17,
 
     /// (18) The waste item is deactivated and cannot be transferred or confirmed.
     /// Returned by: `transfer_waste_v2`, `confirm_waste_details`
     WasteDeactivated = 18,
 
-    /// (19) The waste item is already in the deactivated state.
-    /// Returned by: `deactivate_waste`
-    WasteAlreadyDeactivated = 19,
-
-    /// (20) The waste item has already been confirmed by another participant.
-    /// Returned by: `confirm_waste_details`
-    WasteAlreadyConfirmed = 20,
-
-    /// (21) The waste item has not been confirmed yet.
-    /// Returned by: `reset_waste_confirmation`
-    WasteNotConfirmed = 21,
-
-    /// (22) The current owner attempted to confirm their own waste item.
-    /// Returned by: `confirm_waste_details`
-    SelfConfirmation = 22,
-
-    /// (23) The incentive is not active and cannot be used.
-    /// Returned by: `update_incentive`, `claim_incentive_reward`
-    IncentiveInactive = 23,
-
-    /// (24) The material has not been verified and cannot be used for reward claims.
-    /// Returned by: `claim_incentive_reward`
-    MaterialNotVerified = 24,
-
-    /// (25) The material's waste type does not match the incentive's waste type.
-    /// Returned by: `claim_incentive_reward`
-    WasteTypeMismatch = 25,
-
-    /// (26) The calculated reward is zero — either the budget is exhausted or
-    /// the waste weight is below 1 kg.
-    /// Returned by: `claim_incentive_reward`
-    NoRewardAvailable = 26,
-
     /// (27) The role combination (`from` → `to`) is not a permitted transfer route.
     /// Valid routes: Recycler→Collector, Recycler→Manufacturer, Collector→Manufacturer.
-    /// Returned by: `transfer_waste_v2`
+    /// Returned by: `transfer_waste_v2`, `batch_transfer_waste`
     InvalidTransferRoute = 27,
 
     /// (28) Two addresses that must be different are equal
     /// (e.g. charity address equals admin address).
-    /// Returned by: `set_charity_contract`
+    /// Returned by: `set_charity_contract`, `transfer_material`, `rate_participant`,
+    ///              `offer_payment`
     SameAddress = 28,
 
     /// (29) An arithmetic operation would overflow.
     /// Returned by: any function performing checked arithmetic
     Overflow = 29,
 
-    /// (30) The caller is not the original creator of the resource.
-    /// Returned by: `deactivate_incentive`
-    NotCreator = 30,
-
     /// (31) Insufficient budget for the reward.
     InsufficientBudget = 31,
+
+    /// (32) The participant is registered but has deactivated themselves.
+    /// Returned by: `transfer_waste_v2`, `batch_transfer_waste`, `create_material_batch`,
+    ///              `transfer_material`
+    Inactive = 32,
+
+    /// (33) The participant name is empty.
+    /// Returned by: `register_participant`, `update_name`
+    InvalidName = 33,
+
+    /// (34) The caller's role is not `Recycler`.
+    /// Returned by: `create_material_batch`
+    NotRecycler = 34,
+
+    /// (35) No material batch exists for the given ID.
+    /// Returned by: `transfer_material`, `set_batch_status`, `split_batch`,
+    ///              `merge_batches`, `offer_payment`, `confirm_receipt`, `flag_batch`
+    BatchNotFound = 35,
+
+    /// (37) The contract is paused; state-changing calls are rejected.
+    /// Returned by: any state-changing function while paused
+    Paused = 37,
+
+    /// (38) The two participants have never handed a batch to each other.
+    /// Returned by: `rate_participant`
+    NotCounterparty = 38,
+
+    /// (39) The rater has already rated this target during the current ledger day.
+    /// Returned by: `rate_participant`
+    AlreadyRated = 39,
+
+    /// (40) The requested batch status is not after the current one.
+    /// Returned by: `set_batch_status`
+    InvalidTransition = 40,
+
+    /// (41) The batch has been recycled and can no longer change hands.
+    /// Returned by: `transfer_material`, `split_batch`, `merge_batches`,
+    ///              `offer_payment`, `confirm_receipt`
+    BatchRecycled = 41,
+
+    /// (42) Removing the role would leave the participant with none.
+    /// Returned by: `remove_role`
+    LastRole = 42,
+
+    /// (43) The requested child weights do not add up to the parent batch's weight.
+    /// Returned by: `split_batch`
+    WeightMismatch = 43,
+
+    /// (44) The batches to merge are not all of the same `MaterialKind`.
+    /// Returned by: `merge_batches`
+    KindMismatch = 44,
+
+    /// (45) The caller does not own the specified material batch.
+    /// Returned by: `merge_batches`
+    NotBatchOwner = 45,
+
+    /// (46) Fewer than two distinct batch ids were supplied.
+    /// Returned by: `merge_batches`
+    TooFewBatches = 46,
+
+    /// (47) The batch already has a pending payment offer.
+    /// Returned by: `offer_payment`
+    EscrowExists = 47,
+
+    /// (48) The batch has no pending payment offer.
+    /// Returned by: `confirm_receipt`, `cancel_offer`
+    EscrowNotFound = 48,
+
+    /// (49) The caller's reward-point balance is below the amount to redeem.
+    /// Returned by: `redeem_points`
+    InsufficientPoints = 49,
+
+    /// (50) The contract's reward-token balance, less tokens held in escrow,
+    /// cannot cover the payout.
+    /// Returned by: `redeem_points`
+    InsufficientRewardPool = 50,
+
+    /// (51) The per-ledger registration limit has been reached; retry in a later ledger.
+    /// Returned by: `register_participant`
+    RateLimited = 51,
+
+    /// (52) The address has no role request awaiting approval.
+    /// Returned by: `approve_role`, `reject_role`
+    NoPendingRole = 52,
+
+    /// (53) The batch has no open dispute.
+    /// Returned by: `resolve_dispute`
+    NotDisputed = 53,
+
+    /// (54) The batch has an open dispute and is frozen until the admin resolves it.
+    /// Returned by: `transfer_material`, `set_batch_status`, `split_batch`,
+    ///              `merge_batches`, `offer_payment`, `confirm_receipt`
+    BatchDisputed = 54,
+
+    /// (55) The caller has never held the batch.
+    /// Returned by: `flag_batch`
+    NotCustodian = 55,
+
+    /// (56) The batch already has an open dispute.
+    /// Returned by: `flag_batch`
+    AlreadyDisputed = 56,
+
+    /// (57) No location oracle key has been set via `set_location_oracle`.
+    /// Returned by: `update_location`
+    LocationOracleNotSet = 57,
+
+    /// (58) The location proof's timestamp is in the future or more than five minutes old.
+    /// Returned by: `update_location`
+    StaleLocationProof = 58,
+
+    /// (59) The batch has passed its expiry time, or a new batch's expiry is not in the future.
+    /// Returned by: `transfer_material`, `set_batch_status`, `create_perishable_batch`,
+    ///              `offer_payment`, `confirm_receipt`
+    BatchExpired = 59,
+
+    /// (60) A bulk call was given more entries than it accepts.
+    /// Returned by: `register_batch`
+    TooManyEntries = 60,
+
+    /// (61) No price has been published for the batch's material kind.
+    /// Returned by: `estimate_value`
+    NoPrice = 61,
+
+    /// (62) Unique names are enforced and another participant holds the name.
+    /// Returned by: `register_participant`, `register_batch`, `update_name`
+    NameTaken = 62,
+
+    /// (63) The role flow check is on and no role pair of sender and recipient
+    /// is a permitted downstream hand-over.
+    /// Returned by: `transfer_material`, `offer_payment`, `confirm_receipt`
+    IllegalFlow = 63,
+
+    /// (64) A requested output precision is outside the supported range.
+    /// Returned by: `geohash`
+    InvalidPrecision = 64,
+
+    /// (65) A contact handle is empty or longer than 128 bytes.
+    /// Returned by: `set_contact`
+    InvalidHandle = 65,
+
+    /// (66) A time window's start is after its end.
+    /// Returned by: `weight_in_window`
+    InvalidWindow = 66,
 }
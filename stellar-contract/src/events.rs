@@ -1,6 +1,7 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
 
-use crate::types::{ParticipantRole, WasteType};
+use crate::types::{BatchStatus, Dispute, Escrow, MaterialBatch, MaterialKind, ParticipantRole, WasteType};
+use crate::Participant;
 
 const WASTE_REGISTERED: Symbol = symbol_short!("recycled");
 const DONATION_MADE: Symbol = symbol_short!("donated");
@@ -8,6 +9,10 @@ const WASTE_TRANSFERRED: Symbol = symbol_short!("transfer");
 const WASTE_CONFIRMED: Symbol = symbol_short!("confirmed");
 const PARTICIPANT_REGISTERED: Symbol = symbol_short!("reg");
 const TOKENS_REWARDED: Symbol = symbol_short!("rewarded");
+const PARTICIPANT_DEACTIVATED: Symbol = symbol_short!("deact");
+const MATERIAL_CREATED: Symbol = symbol_short!("mat_new");
+const MATERIAL_TRANSFERRED: Symbol = symbol_short!("mat_xfer");
+const POINTS_AWARDED: Symbol = symbol_short!("reward");
 
 /// Emit event when waste is registered
 pub fn emit_waste_registered(
@@ -25,6 +30,132 @@ pub fn emit_waste_registered(
     );
 }
 
+/// Emit event when a material batch is created
+pub fn emit_material_created(
+    env: &Env,
+    batch_id: u64,
+    owner: &Address,
+    kind: MaterialKind,
+    weight_grams: u64,
+) {
+    env.events().publish(
+        (MATERIAL_CREATED, batch_id),
+        (owner, kind, weight_grams),
+    );
+}
+
+/// Schema version of the `mat_xfer` event payload. Bump whenever the data
+/// tuple changes shape so indexers can branch on it.
+pub const XFER_EVENT_VERSION: u32 = 1;
+
+/// Emit event when a material batch changes hands.
+///
+/// `batch` is the record after the transfer, so its owner is the recipient.
+/// Data is `(version, from, to, kind, weight_grams)`, letting indexers
+/// track material flows without reading the batch back.
+pub fn emit_material_transferred(env: &Env, batch: &MaterialBatch, from: &Address) {
+    env.events().publish(
+        (MATERIAL_TRANSFERRED, batch.id),
+        (
+            XFER_EVENT_VERSION,
+            from.clone(),
+            batch.owner.clone(),
+            batch.kind,
+            batch.weight_grams,
+        ),
+    );
+}
+
+/// Emit event when an admin forcibly reassigns a material batch
+pub fn emit_batch_reassigned(env: &Env, batch_id: u64, admin: &Address, from: &Address, to: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_xfer"), batch_id),
+        (admin.clone(), from.clone(), to.clone()),
+    );
+}
+
+/// Emit event when an admin removes a participant
+pub fn emit_participant_removed(env: &Env, address: &Address, admin: &Address) {
+    env.events().publish((symbol_short!("removed"), address), admin);
+}
+
+/// Emit event when a participant's reputation changes
+pub fn emit_participant_rated(env: &Env, target: &Address, rater: &Address, reputation: i32) {
+    env.events().publish((symbol_short!("rated"), target), (rater, reputation));
+}
+
+/// Emit event when reward points are credited
+pub fn emit_points_awarded(env: &Env, address: &Address, amount: u64) {
+    env.events().publish((POINTS_AWARDED, address), amount);
+}
+
+/// Emit event when points are redeemed for reward tokens
+pub fn emit_points_redeemed(env: &Env, address: &Address, points: u64, tokens: i128) {
+    env.events().publish((symbol_short!("redeem"), address), (points, tokens));
+}
+
+/// Emit event when a material batch moves to a new processing stage
+pub fn emit_batch_status_changed(env: &Env, batch_id: u64, status: BatchStatus) {
+    env.events().publish((symbol_short!("status"), batch_id), status);
+}
+
+/// Emit event when a material batch is split into children
+pub fn emit_batch_split(env: &Env, parent_id: u64, child_ids: &Vec<u64>) {
+    env.events().publish((symbol_short!("split"), parent_id), child_ids.clone());
+}
+
+/// Emit event when material batches are merged into a new one
+pub fn emit_batches_merged(env: &Env, merged_id: u64, consumed_ids: &Vec<u64>) {
+    env.events().publish((symbol_short!("merged"), merged_id), consumed_ids.clone());
+}
+
+/// Emit event when an expired batch is purged
+pub fn emit_batch_expired(env: &Env, batch_id: u64, owner: &Address) {
+    env.events().publish((symbol_short!("expired"), batch_id), owner);
+}
+
+/// Emit event when the price of a material kind changes
+pub fn emit_price_set(env: &Env, kind: MaterialKind, price_per_gram: i128) {
+    env.events().publish((symbol_short!("price"), kind), price_per_gram);
+}
+
+/// Emit event when a batch is flagged as disputed
+pub fn emit_batch_disputed(env: &Env, batch_id: u64, dispute: &Dispute) {
+    env.events().publish(
+        (symbol_short!("dispute"), batch_id),
+        (dispute.flagger.clone(), dispute.reason.clone()),
+    );
+}
+
+/// Emit event when the admin closes a dispute
+pub fn emit_dispute_resolved(env: &Env, batch_id: u64, admin: &Address) {
+    env.events().publish((symbol_short!("resolved"), batch_id), admin);
+}
+
+/// Emit event when a buyer locks payment for a batch
+pub fn emit_escrow_created(env: &Env, batch_id: u64, escrow: &Escrow) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_new"), batch_id),
+        (escrow.buyer.clone(), escrow.token.clone(), escrow.amount),
+    );
+}
+
+/// Emit event when escrowed payment is released to the seller
+pub fn emit_escrow_paid(env: &Env, batch_id: u64, seller: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_paid"), batch_id),
+        (seller.clone(), amount),
+    );
+}
+
+/// Emit event when escrowed payment is refunded to the buyer
+pub fn emit_escrow_cancelled(env: &Env, batch_id: u64, buyer: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_cancel"), batch_id),
+        (buyer.clone(), amount),
+    );
+}
+
 /// Emit event when a donation is made to charity
 pub fn emit_donation_made(
     env: &Env,
@@ -63,18 +194,33 @@ pub fn emit_waste_confirmed(
     );
 }
 
+/// Schema version of the `reg` event payload. Bump whenever the data tuple
+/// changes shape so indexers can branch on it.
+pub const REG_EVENT_VERSION: u32 = 1;
+
 /// Emit event when a participant registers
-pub fn emit_participant_registered(
-    env: &Env,
-    address: &Address,
-    role: ParticipantRole,
-    name: Symbol,
-    latitude: i128,
-    longitude: i128,
-) {
+///
+/// Data: `(version, role, name, latitude, longitude, registered_at)`. The
+/// version is a `u32` because contract values have no byte-sized integer.
+pub fn emit_participant_registered(env: &Env, participant: &Participant) {
     env.events().publish(
-        (PARTICIPANT_REGISTERED, address),
-        (role.to_u32(), name, latitude, longitude),
+        (PARTICIPANT_REGISTERED, &participant.address),
+        (
+            REG_EVENT_VERSION,
+            participant.role.to_u32(),
+            participant.name.clone(),
+            participant.latitude,
+            participant.longitude,
+            participant.registered_at,
+        ),
+    );
+}
+
+/// Emit event when a participant deactivates themselves
+pub fn emit_participant_deactivated(env: &Env, address: &Address) {
+    env.events().publish(
+        (PARTICIPANT_DEACTIVATED, address),
+        env.ledger().timestamp(),
     );
 }
 
@@ -97,13 +243,39 @@ pub fn emit_participant_location_updated(
     address: &Address,
     latitude: i128,
     longitude: i128,
+    verified: bool,
 ) {
     env.events().publish(
         (symbol_short!("loc_upd"), address),
-        (latitude, longitude),
+        (latitude, longitude, verified),
     );
 }
 
+/// Emit event when a participant asks the admin for a role
+pub fn emit_role_requested(env: &Env, address: &Address, role: ParticipantRole) {
+    env.events().publish((symbol_short!("role_req"), address), role);
+}
+
+/// Emit event when the admin grants a requested role
+pub fn emit_role_approved(env: &Env, address: &Address, role: ParticipantRole) {
+    env.events().publish((symbol_short!("role_ok"), address), role);
+}
+
+/// Emit event when the admin turns down a requested role
+pub fn emit_role_rejected(env: &Env, address: &Address, role: ParticipantRole) {
+    env.events().publish((symbol_short!("role_no"), address), role);
+}
+
+/// Emit event when a participant changes their display name
+pub fn emit_participant_name_updated(env: &Env, address: &Address, name: &Symbol) {
+    env.events().publish((symbol_short!("name_upd"), address), name.clone());
+}
+
+/// Emit event when the first admin is set
+pub fn emit_contract_initialized(env: &Env, admin: &Address) {
+    env.events().publish((symbol_short!("init"),), admin);
+}
+
 pub fn emit_admin_transferred(env: &Env, previous_admin: &Address) {
     env.events().publish((symbol_short!("adm_xfr"),), previous_admin);
 }
@@ -115,3 +287,7 @@ pub fn emit_contract_paused(env: &Env, admin: &Address) {
 pub fn emit_contract_unpaused(env: &Env, admin: &Address) {
     env.events().publish((symbol_short!("unpaused"),), admin);
 }
+
+pub fn emit_contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) {
+    env.events().publish((symbol_short!("upgrade"), admin), new_wasm_hash.clone());
+}
@@ -0,0 +1,66 @@
+//! Canonical direction of material through the supply chain.
+//!
+//! With the flow check on, a batch may only move downstream:
+//!
+//! | From \ To     | Recycler | Collector | Manufacturer |
+//! |---------------|----------|-----------|--------------|
+//! | Recycler      | no       | yes       | no           |
+//! | Collector     | no       | yes       | yes          |
+//! | Manufacturer  | no       | no        | no           |
+//!
+//! Collectors may pass material between themselves, but nothing goes back
+//! upstream and recyclers cannot skip the collection step. A participant
+//! holding several roles may use any of them, so a hand-over is allowed if
+//! any role of the sender and any role of the recipient form a `yes` pair.
+//!
+//! Waste records follow their own routes; see `is_valid_transfer`.
+
+use soroban_sdk::Env;
+
+use crate::storage::DataKey;
+use crate::{Participant, ParticipantRole};
+
+/// Whether `transfer_material` enforces the canonical flow. Off by default.
+pub fn enforced(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleFlowCheck)
+        .unwrap_or(false)
+}
+
+/// Turn canonical-flow enforcement on or off.
+pub fn set_enforced(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::RoleFlowCheck, &enabled);
+}
+
+/// Whether material may move from a holder of `from` to a holder of `to`.
+pub fn role_permits(from: ParticipantRole, to: ParticipantRole) -> bool {
+    matches!(
+        (from, to),
+        (ParticipantRole::Recycler, ParticipantRole::Collector)
+            | (ParticipantRole::Collector, ParticipantRole::Collector)
+            | (ParticipantRole::Collector, ParticipantRole::Manufacturer)
+    )
+}
+
+/// Whether any role held by `from` and any held by `to` form a permitted pair.
+pub fn permits(from: &Participant, to: &Participant) -> bool {
+    from.held_roles()
+        .any(|from_role| to.held_roles().any(|to_role| role_permits(from_role, to_role)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_table() {
+        use ParticipantRole::*;
+        let allowed = [(Recycler, Collector), (Collector, Collector), (Collector, Manufacturer)];
+        for from in ParticipantRole::ALL {
+            for to in ParticipantRole::ALL {
+                assert_eq!(role_permits(from, to), allowed.contains(&(from, to)), "{from:?} -> {to:?}");
+            }
+        }
+    }
+}
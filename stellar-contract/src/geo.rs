@@ -0,0 +1,229 @@
+//! Great-circle distance using fixed-point arithmetic.
+//!
+//! Contracts have no floating point, so angles are carried as radians scaled
+//! by [`SCALE`] in `i128`. `sin` is a Taylor series after range reduction and
+//! `asin` is found by bisection on `sin`, which keeps the error well under
+//! 0.1% for any pair of points.
+//!
+//! Also encodes [geohashes](https://en.wikipedia.org/wiki/Geohash) for
+//! off-chain proximity bucketing.
+
+/// Fixed-point scale: 1.0 == 1_000_000_000.
+const SCALE: i128 = 1_000_000_000;
+const PI: i128 = 3_141_592_654;
+const HALF_PI: i128 = PI / 2;
+const TWO_PI: i128 = PI * 2;
+
+/// Mean Earth radius in metres.
+const EARTH_RADIUS_M: i128 = 6_371_000;
+
+/// Convert microdegrees to scaled radians.
+fn to_radians(microdegrees: i128) -> i128 {
+    microdegrees * PI / 180_000_000
+}
+
+/// `sin(x)` for scaled radians `x`.
+fn sin(x: i128) -> i128 {
+    // Reduce to [-π, π], then fold into [-π/2, π/2] where the series
+    // converges quickly.
+    let mut x = x % TWO_PI;
+    if x > PI {
+        x -= TWO_PI;
+    } else if x < -PI {
+        x += TWO_PI;
+    }
+    if x > HALF_PI {
+        x = PI - x;
+    } else if x < -HALF_PI {
+        x = -PI - x;
+    }
+
+    let x2 = x * x / SCALE;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..8 {
+        term = -term * x2 / SCALE / ((2 * n) * (2 * n + 1));
+        sum += term;
+    }
+    sum
+}
+
+fn cos(x: i128) -> i128 {
+    sin(x + HALF_PI)
+}
+
+/// `asin(h)` for scaled `h` in `[0, 1]`, returning scaled radians in `[0, π/2]`.
+fn asin(h: i128) -> i128 {
+    let (mut lo, mut hi) = (0, HALF_PI);
+    for _ in 0..40 {
+        let mid = (lo + hi) / 2;
+        if sin(mid) < h {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Integer square root (floor).
+fn isqrt(n: i128) -> i128 {
+    if n < 2 {
+        return n.max(0);
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Great-circle distance in metres between two points given in microdegrees.
+///
+/// Uses the Haversine formula on a spherical Earth of radius 6 371 km.
+pub fn distance_meters(lat1: i64, lon1: i64, lat2: i64, lon2: i64) -> u64 {
+    let phi1 = to_radians(lat1 as i128);
+    let phi2 = to_radians(lat2 as i128);
+    let d_phi = phi2 - phi1;
+    let d_lambda = to_radians(lon2 as i128 - lon1 as i128);
+
+    let s_phi = sin(d_phi / 2);
+    let s_lambda = sin(d_lambda / 2);
+    let a = s_phi * s_phi / SCALE
+        + cos(phi1) * cos(phi2) / SCALE * s_lambda / SCALE * s_lambda / SCALE;
+    let a = a.clamp(0, SCALE);
+
+    let c = 2 * asin(isqrt(a * SCALE));
+    (EARTH_RADIUS_M * c / SCALE) as u64
+}
+
+/// Longest geohash [`geohash`] will produce, about 1 m × 0.6 m of precision.
+pub const GEOHASH_MAX_PRECISION: u32 = 10;
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Fill `out` with the geohash of a point given in microdegrees, one
+/// character per byte.
+///
+/// Bisection runs on coordinates scaled up by 2^26 so every midpoint is
+/// exact for the 25 bits per axis a 10-character hash needs.
+pub fn geohash(lat: i64, lon: i64, out: &mut [u8]) {
+    const SHIFT: u32 = 26;
+    let mut lat_range = (-90_000_000i128 << SHIFT, 90_000_000i128 << SHIFT);
+    let mut lon_range = (-180_000_000i128 << SHIFT, 180_000_000i128 << SHIFT);
+    let (lat, lon) = ((lat as i128) << SHIFT, (lon as i128) << SHIFT);
+
+    // Bits alternate longitude, latitude, starting with longitude.
+    let mut even = true;
+    for c in out.iter_mut() {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = if even {
+                (&mut lon_range, lon)
+            } else {
+                (&mut lat_range, lat)
+            };
+            let mid = (range.0 + range.1) / 2;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        *c = GEOHASH_ALPHABET[index];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assert `actual` is within 0.5% of `expected`.
+    fn assert_close(actual: u64, expected: u64) {
+        let diff = actual.abs_diff(expected);
+        assert!(
+            diff * 200 <= expected,
+            "distance {} not within 0.5% of {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_same_point_is_zero() {
+        assert_eq!(distance_meters(40_712_800, -74_006_000, 40_712_800, -74_006_000), 0);
+    }
+
+    #[test]
+    fn test_london_paris() {
+        let d = distance_meters(51_507_400, -127_800, 48_856_600, 2_352_200);
+        assert_close(d, 343_556);
+    }
+
+    #[test]
+    fn test_new_york_los_angeles() {
+        let d = distance_meters(40_712_800, -74_006_000, 34_052_200, -118_243_700);
+        assert_close(d, 3_935_746);
+    }
+
+    #[test]
+    fn test_sydney_tokyo_crosses_equator() {
+        let d = distance_meters(-33_868_800, 151_209_300, 35_676_200, 139_650_300);
+        assert_close(d, 7_825_818);
+    }
+
+    #[test]
+    fn test_antipodes() {
+        let d = distance_meters(0, 0, 0, 180_000_000);
+        assert_close(d, 20_015_086);
+    }
+
+    #[test]
+    fn test_wraps_across_antimeridian() {
+        let d = distance_meters(0, 179_500_000, 0, -179_500_000);
+        assert_close(d, 111_194);
+    }
+
+    fn hash(lat: i64, lon: i64, precision: usize) -> [u8; 10] {
+        let mut buf = [b' '; 10];
+        geohash(lat, lon, &mut buf[..precision]);
+        buf
+    }
+
+    #[test]
+    fn test_geohash_reference_values() {
+        // Jutland, the example from the original geohash announcement
+        assert_eq!(&hash(57_649_110, 10_407_440, 10), b"u4pruydqqv");
+        // Northern Spain
+        assert_eq!(&hash(42_600_000, -5_600_000, 5)[..5], b"ezs42");
+    }
+
+    #[test]
+    fn test_geohash_corners() {
+        assert_eq!(&hash(0, 0, 6)[..6], b"s00000");
+        assert_eq!(&hash(-90_000_000, -180_000_000, 4)[..4], b"0000");
+        assert_eq!(&hash(90_000_000, 180_000_000, 4)[..4], b"zzzz");
+        assert_eq!(&hash(-1, -1, 4)[..4], b"7zzz");
+    }
+
+    #[test]
+    fn test_geohash_prefixes_agree() {
+        let long = hash(40_712_800, -74_006_000, 10);
+        for precision in 1..10 {
+            assert_eq!(hash(40_712_800, -74_006_000, precision)[..precision], long[..precision]);
+        }
+        assert_eq!(&long[..5], b"dr5re");
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let ab = distance_meters(51_507_400, -127_800, 48_856_600, 2_352_200);
+        let ba = distance_meters(48_856_600, 2_352_200, 51_507_400, -127_800);
+        assert_eq!(ab, ba);
+    }
+}
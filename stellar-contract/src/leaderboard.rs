@@ -0,0 +1,136 @@
+//! Bounded ranking of producers by total batch weight.
+//!
+//! Sorting every participant on read would cost a storage read per address,
+//! so the top [`LEADERBOARD_SIZE`] entries are kept sorted in instance
+//! storage and patched each time a batch is created.
+//!
+//! Ties are broken by who reached the total first: an address only moves
+//! ahead of another by strictly exceeding its weight.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::DataKey;
+
+/// Number of entries the leaderboard keeps.
+pub const LEADERBOARD_SIZE: u32 = 20;
+
+/// Current leaderboard, heaviest first.
+pub fn entries(env: &Env) -> Vec<(Address, u64)> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Leaderboard)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Place `address` according to its new running total `weight_grams`.
+///
+/// Totals only grow, so an existing entry can only move up.
+pub fn record(env: &Env, address: &Address, weight_grams: u64) {
+    let mut board = entries(env);
+    if let Some(pos) = board.iter().position(|(a, _)| a == *address) {
+        board.remove(pos as u32);
+    } else if board.len() >= LEADERBOARD_SIZE
+        && board.last().is_some_and(|(_, w)| w >= weight_grams)
+    {
+        return;
+    }
+
+    let pos = board
+        .iter()
+        .position(|(_, w)| w < weight_grams)
+        .map_or(board.len(), |p| p as u32);
+    board.insert(pos, (address.clone(), weight_grams));
+    if board.len() > LEADERBOARD_SIZE {
+        board.pop_back();
+    }
+    env.storage().instance().set(&DataKey::Leaderboard, &board);
+}
+
+/// Drop `address` from the leaderboard. No-op if absent.
+pub fn remove(env: &Env, address: &Address) {
+    let mut board = entries(env);
+    if let Some(pos) = board.iter().position(|(a, _)| a == *address) {
+        board.remove(pos as u32);
+        env.storage().instance().set(&DataKey::Leaderboard, &board);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScavengerContract;
+    use soroban_sdk::{testutils::Address as _, vec};
+
+    fn weights(env: &Env) -> Vec<u64> {
+        let mut out = Vec::new(env);
+        for (_, w) in entries(env).iter() {
+            out.push_back(w);
+        }
+        out
+    }
+
+    #[test]
+    fn test_entries_stay_sorted_descending() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            for w in [30, 10, 50, 20, 40] {
+                record(&env, &Address::generate(&env), w);
+            }
+            assert_eq!(weights(&env), vec![&env, 50, 40, 30, 20, 10]);
+        });
+    }
+
+    #[test]
+    fn test_existing_entry_moves_up() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let a = Address::generate(&env);
+            let b = Address::generate(&env);
+            record(&env, &a, 10);
+            record(&env, &b, 20);
+            record(&env, &a, 25);
+
+            let board = entries(&env);
+            assert_eq!(board.len(), 2);
+            assert_eq!(board.get(0).unwrap(), (a, 25));
+            assert_eq!(board.get(1).unwrap(), (b, 20));
+        });
+    }
+
+    #[test]
+    fn test_ties_keep_earlier_entry_ahead() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let first = Address::generate(&env);
+            let second = Address::generate(&env);
+            record(&env, &first, 10);
+            record(&env, &second, 10);
+
+            assert_eq!(entries(&env).get(0).unwrap().0, first);
+        });
+    }
+
+    #[test]
+    fn test_bounded_to_leaderboard_size() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            for w in 1..=LEADERBOARD_SIZE as u64 + 5 {
+                record(&env, &Address::generate(&env), w);
+            }
+            let board = weights(&env);
+            assert_eq!(board.len(), LEADERBOARD_SIZE);
+            assert_eq!(board.first().unwrap(), LEADERBOARD_SIZE as u64 + 5);
+            assert_eq!(board.last().unwrap(), 6);
+
+            // Too light to enter a full board.
+            let outsider = Address::generate(&env);
+            record(&env, &outsider, 6);
+            assert_eq!(weights(&env), board);
+            assert!(!entries(&env).iter().any(|(a, _)| a == outsider));
+        });
+    }
+}
@@ -1,19 +1,31 @@
 #![no_std]
 
+mod carbon;
 mod errors;
 mod events;
+mod flow;
+mod geo;
+mod leaderboard;
+mod migrate;
+mod pricing;
+mod reputation;
+mod rewards;
+mod storage;
 mod types;
 mod validation;
 mod test_transfer_path_validation;
 
 pub use errors::Error;
+pub use storage::DEFAULT_TTL_LEDGERS;
+pub use types::{to_kg, GRAMS_PER_KG};
 pub use types::{
-    GlobalMetrics, Incentive, Material, ParticipantRole, RecyclingStats, TransferItemType,
+    BatchOrigin, BatchReport, BatchStatus, ContactInfo, ContactKind, CustodyEntry, Dispute, Escrow, GlobalMetrics, Incentive, LocationProof, Material, MaterialBatch, MaterialKind, ParticipantRole, RecyclingStats, RewardSchedule, TransferItemType,
     TransferRecord, TransferStatus, Waste, WasteTransfer, WasteType,
 };
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    xdr::ToXdr, Address, BytesN, Env, String, Symbol, Vec,
 };
 
 
@@ -25,12 +37,23 @@ const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
 const TOTAL_TOKENS: Symbol = symbol_short!("TOT_TKN");
 const REENTRANCY_GUARD: Symbol = symbol_short!("RE_GUARD");
 const TOKEN_ADDR: Symbol = symbol_short!("TKN_ADDR");
-const PART_INDEX: Symbol = symbol_short!("PART_IDX");
 const PAUSED: Symbol = symbol_short!("PAUSED");
 
 /// Maximum allowed waste weight per submission (1 000 000 kg in grams).
 const MAX_WASTE_WEIGHT: u128 = 1_000_000_000;
 
+/// Maximum number of records returned by a single `list_participants` call.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of addresses returned by `participants_in_bbox`.
+const MAX_BBOX_RESULTS: u32 = 100;
+
+/// Maximum number of entries accepted by `register_batch`.
+const MAX_BULK_REGISTRATIONS: u32 = 50;
+
+/// Oldest location proof, in seconds, `update_location` accepts.
+const LOCATION_PROOF_MAX_AGE: u64 = 300;
+
 /// Reward distribution percentages stored as a single instance-storage entry.
 ///
 /// Consolidating `collector_percentage` and `owner_percentage` into one struct
@@ -51,17 +74,37 @@ pub struct RewardConfig {
 }
 
 /// On-chain record for a registered supply-chain participant.
+///
+/// Coordinates are fixed-point microdegrees: multiply degrees by 1e6 before
+/// submitting (52.520008° → `52_520_008`). Latitude must lie within
+/// `[-90_000_000, 90_000_000]` and longitude within
+/// `[-180_000_000, 180_000_000]`; anything else is rejected.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Participant {
     pub address: Address,
+    /// Primary role: the one given at registration or via `update_role`.
+    /// Always one of `roles`.
     pub role: ParticipantRole,
+    /// Every role held, as a bitmask of [`ParticipantRole::bit`]. Role checks
+    /// consult this set rather than `role` alone.
+    pub roles: u32,
     pub name: soroban_sdk::Symbol,
     /// Latitude in microdegrees (e.g. 1_000_000 = 1.0°).
     pub latitude: i128,
     /// Longitude in microdegrees.
     pub longitude: i128,
+    /// `true` only if the current coordinates came with a proof signed by
+    /// the location oracle; see [`ScavengerContract::update_location`].
+    pub location_verified: bool,
     pub is_registered: bool,
+    /// `false` while the participant has opted out via
+    /// [`ScavengerContract::deactivate_participant`]. Inactive participants
+    /// keep their record but cannot send or receive material.
+    pub active: bool,
+    /// Trust score set by counterparties via
+    /// [`ScavengerContract::rate_participant`], within `[-1000, 1000]`.
+    pub reputation: i32,
     /// Cumulative grams of waste processed.
     pub total_waste_processed: u128,
     /// Cumulative reward tokens earned.
@@ -70,6 +113,32 @@ pub struct Participant {
     pub registered_at: u64,
 }
 
+impl Participant {
+    /// Checks if the participant holds `role`
+    pub fn has_role(&self, role: ParticipantRole) -> bool {
+        self.roles & role.bit() != 0
+    }
+
+    /// Iterates over the roles the participant holds
+    pub fn held_roles(&self) -> impl Iterator<Item = ParticipantRole> {
+        let roles = self.roles;
+        ParticipantRole::ALL.into_iter().filter(move |r| roles & r.bit() != 0)
+    }
+}
+
+/// One entry of a [`ScavengerContract::register_batch`] call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisterInput {
+    pub address: Address,
+    pub role: ParticipantRole,
+    pub name: soroban_sdk::Symbol,
+    /// Latitude in microdegrees.
+    pub latitude: i128,
+    /// Longitude in microdegrees.
+    pub longitude: i128,
+}
+
 /// Combined view of a participant and their recycling statistics.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -118,9 +187,35 @@ impl ScavengerContract {
             panic!("Admin already initialized");
         }
 
-        let mut admins = Vec::new(&env);
-        admins.push_back(admin);
+        Self::store_initial_admin(&env, admin);
+    }
+
+    /// Typed-error variant of [`initialize_admin`].
+    ///
+    /// Stores `admin` as the sole administrator and emits an `init` event.
+    ///
+    /// # Parameters
+    /// - `admin`: Address that will hold admin privileges. Must sign the transaction.
+    ///
+    /// # Errors
+    /// - [`Error::AlreadyInitialized`] if an admin has already been set by
+    ///   either entry point.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        if env.storage().instance().has(&ADMINS) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        Self::store_initial_admin(&env, admin);
+        Ok(())
+    }
+
+    fn store_initial_admin(env: &Env, admin: Address) {
+        let mut admins = Vec::new(env);
+        admins.push_back(admin.clone());
         env.storage().instance().set(&ADMINS, &admins);
+        events::emit_contract_initialized(env, &admin);
     }
 
     /// Get the current admin addresses.
@@ -207,8 +302,7 @@ impl ScavengerContract {
     fn only_registered(env: &Env, caller: &Address) {
         caller.require_auth();
         
-        let key = (caller.clone(),);
-        let participant: Option<Participant> = env.storage().instance().get(&key);
+        let participant = storage::get_participant(env, caller);
         
         match participant {
             Some(p) if p.is_registered => {},
@@ -223,25 +317,20 @@ impl ScavengerContract {
     fn only_manufacturer(env: &Env, caller: &Address) {
         caller.require_auth();
         
-        let key = (caller.clone(),);
-        let participant: Participant = env
-            .storage()
-            .instance()
-            .get(&key)
+        let participant: Participant = storage::get_participant(env, caller)
             .expect("Caller is not a registered participant");
         
         if !participant.is_registered {
             panic!("Caller is not a registered participant");
         }
         
-        if !participant.role.can_manufacture() {
+        if !participant.held_roles().any(|r| r.can_manufacture()) {
             panic!("Caller is not a manufacturer");
         }
     }
 
     fn require_registered(env: &Env, address: &Address) {
-        let key = (address.clone(),);
-        let participant: Option<Participant> = env.storage().instance().get(&key);
+        let participant = storage::get_participant(env, address);
 
         match participant {
             Some(p) if p.is_registered => {}
@@ -349,11 +438,7 @@ impl ScavengerContract {
         }
 
         // Validate donor has enough earned token balance.
-        let donor_key = (donor.clone(),);
-        let mut participant: Participant = env
-            .storage()
-            .instance()
-            .get(&donor_key)
+        let mut participant: Participant = storage::get_participant(&env, &donor)
             .expect("Caller is not a registered participant");
         let donation_amount = amount as u128;
         if participant.total_tokens_earned < donation_amount {
@@ -361,7 +446,7 @@ impl ScavengerContract {
             panic!("Insufficient balance");
         }
         participant.total_tokens_earned -= donation_amount;
-        env.storage().instance().set(&donor_key, &participant);
+        storage::save_participant(&env, &participant);
 
         // Get charity contract address
         let charity_contract = env
@@ -548,17 +633,12 @@ impl ScavengerContract {
         }
 
         // Update recipient's total tokens earned
-        let recipient_key = (recipient.clone(),);
-        if let Some(mut participant) = env
-            .storage()
-            .instance()
-            .get::<_, Participant>(&recipient_key)
-        {
+        if let Some(mut participant) = storage::get_participant(&env, &recipient) {
             participant.total_tokens_earned = participant
                 .total_tokens_earned
                 .checked_add(amount as u128)
                 .expect("Token overflow");
-            env.storage().instance().set(&recipient_key, &participant);
+            storage::save_participant(&env, &participant);
         }
 
         // Update global total tokens
@@ -577,11 +657,13 @@ impl ScavengerContract {
 
     // ========== Participant Storage Functions ==========
 
-    /// Store a participant record
-    /// Internal helper function for efficient participant storage
-    fn set_participant(env: &Env, address: &Address, participant: &Participant) {
-        let key = (address.clone(),);
-        env.storage().instance().set(&key, participant);
+    /// Check whether an address has ever registered, without loading the
+    /// participant record.
+    ///
+    /// Deactivated and deregistered participants still count; use
+    /// [`Self::is_participant_registered`] to exclude the latter.
+    pub fn is_registered(env: Env, address: Address) -> bool {
+        storage::has_participant(&env, &address)
     }
 
     /// Check whether an address is a registered participant.
@@ -589,8 +671,7 @@ impl ScavengerContract {
     /// # Returns
     /// `true` if the address exists in storage and `is_registered == true`.
     pub fn is_participant_registered(env: Env, address: Address) -> bool {
-        let key = (address,);
-        if let Some(p) = env.storage().instance().get::<_, Participant>(&key) {
+        if let Some(p) = storage::get_participant(&env, &address) {
             p.is_registered
         } else {
             false
@@ -612,8 +693,14 @@ impl ScavengerContract {
     /// The newly created [`Participant`] record.
     ///
     /// # Errors
-    /// - Panics `"Participant already registered"` on duplicate registration.
-    /// - Panics on invalid coordinates (see [`validation::validate_coordinates`]).
+    /// - [`Error::InvalidName`] if `name` is empty.
+    /// - [`Error::InvalidCoordinates`] if latitude or longitude is out of range.
+    /// - [`Error::AlreadyRegistered`] if a record already exists for `address`,
+    ///   including one that has since been deregistered.
+    /// - [`Error::NameTaken`] if unique names are enforced and `name` is held
+    ///   by someone else; see [`Self::set_enforce_unique_names`].
+    /// - [`Error::RateLimited`] if the per-ledger registration limit has been
+    ///   reached; see [`Self::set_registration_limit`].
     ///
     /// # Example
     /// ```text
@@ -626,53 +713,98 @@ impl ScavengerContract {
         name: soroban_sdk::Symbol,
         latitude: i128,
         longitude: i128,
-    ) -> Participant {
+    ) -> Result<Participant, Error> {
         Self::require_not_paused(&env);
         address.require_auth();
 
-        // Validate coordinates
-        validation::validate_coordinates(latitude, longitude);
+        storage::record_registration(&env)?;
+        Self::register(&env, address, role, name, latitude, longitude)
+    }
+
+    /// Register many participants in one call (admin only).
+    ///
+    /// Each entry is validated exactly as in [`Self::register_participant`]
+    /// and gets the usual `reg` event. The call is atomic: if any entry is
+    /// rejected, none are registered. The per-ledger registration limit does
+    /// not apply, since only the admin can call this.
+    ///
+    /// # Parameters
+    /// - `admin`: Contract admin. Must sign.
+    /// - `participants`: Up to 50 entries to register.
+    ///
+    /// # Returns
+    /// The number of participants registered.
+    ///
+    /// # Errors
+    /// - [`Error::TooManyEntries`] if more than 50 entries are supplied.
+    /// - Any error returned by [`Self::register_participant`] for an entry.
+    pub fn register_batch(env: Env, admin: Address, participants: Vec<RegisterInput>) -> Result<u32, Error> {
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        if participants.len() > MAX_BULK_REGISTRATIONS {
+            return Err(Error::TooManyEntries);
+        }
+        for input in participants.iter() {
+            Self::register(&env, input.address, input.role, input.name, input.latitude, input.longitude)?;
+        }
+        Ok(participants.len())
+    }
 
-        // Check if already registered
-        if Self::is_participant_registered(env.clone(), address.clone()) {
-            panic!("Participant already registered");
+    /// Validate and store a new participant, without auth or rate limiting.
+    fn register(
+        env: &Env,
+        address: Address,
+        role: ParticipantRole,
+        name: soroban_sdk::Symbol,
+        latitude: i128,
+        longitude: i128,
+    ) -> Result<Participant, Error> {
+        validation::check_name(env, &name)?;
+        validation::check_coordinates(latitude, longitude)?;
+
+        // Reject any existing record, not just active ones, so a deregistered
+        // address cannot overwrite its history by registering again.
+        if storage::get_participant(env, &address).is_some() {
+            return Err(Error::AlreadyRegistered);
         }
+        storage::claim_name(env, &name, &address)?;
 
         let participant = Participant {
             address: address.clone(),
             role,
+            roles: role.bit(),
             name: name.clone(),
             latitude,
             longitude,
+            location_verified: false,
             is_registered: true,
+            active: true,
+            reputation: 0,
             total_waste_processed: 0,
             total_tokens_earned: 0,
             registered_at: env.ledger().timestamp(),
         };
 
         // Store participant using helper function
-        Self::set_participant(&env, &address, &participant);
+        storage::save_participant(env, &participant);
+        // Counted before the index grows; see `storage::participant_count`.
+        storage::increment_participant_count(env);
 
         // Add to participant index
         let mut participant_index: Vec<Address> = env
             .storage()
             .instance()
-            .get(&PART_INDEX)
-            .unwrap_or(Vec::new(&env));
+            .get(&storage::PART_INDEX)
+            .unwrap_or(Vec::new(env));
         participant_index.push_back(address.clone());
-        env.storage().instance().set(&PART_INDEX, &participant_index);
+        env.storage().instance().set(&storage::PART_INDEX, &participant_index);
+        storage::add_to_role_index(env, role, &address);
 
         // Emit event
-        events::emit_participant_registered(
-            &env,
-            &address,
-            role.clone(),
-            name.clone(),
-            latitude,
-            longitude,
-        );
+        events::emit_participant_registered(env, &participant);
 
-        participant
+        Ok(participant)
     }
 
     /// Update participant statistics after processing waste
@@ -683,8 +815,7 @@ impl ScavengerContract {
         waste_weight: u64,
         tokens_earned: u64,
     ) {
-        let key = (address.clone(),);
-        if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
+        if let Some(mut participant) = storage::get_participant(env, address) {
             // Use checked arithmetic to prevent overflow
             participant.total_waste_processed = participant
                 .total_waste_processed
@@ -696,7 +827,7 @@ impl ScavengerContract {
                 .checked_add(tokens_earned as u128)
                 .expect("Overflow in total_tokens_earned");
 
-            env.storage().instance().set(&key, &participant);
+            storage::save_participant(env, &participant);
 
             // Update global total tokens if tokens were earned
             if tokens_earned > 0 {
@@ -733,10 +864,8 @@ impl ScavengerContract {
 
         // Reward collectors — one read per unique transfer recipient
         for transfer in transfers.iter() {
-            let key = (transfer.to.clone(),);
-            let participant: Option<Participant> = env.storage().instance().get(&key);
-            if let Some(p) = participant {
-                if matches!(p.role, ParticipantRole::Collector) {
+            if let Some(p) = storage::get_participant(env, &transfer.to) {
+                if p.has_role(ParticipantRole::Collector) {
                     total_distributed += collector_share;
                     Self::update_participant_stats(env, &transfer.to, 0, collector_share as u64);
                     events::emit_tokens_rewarded(env, &transfer.to, collector_share, waste_id);
@@ -751,13 +880,12 @@ impl ScavengerContract {
             let submitter_total = owner_share + recycler_amount; // = total_reward - total_distributed
 
             if submitter_total > 0 {
-                let key = (material.submitter.clone(),);
-                if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
+                if let Some(mut participant) = storage::get_participant(env, &material.submitter) {
                     participant.total_tokens_earned = participant
                         .total_tokens_earned
                         .checked_add(submitter_total as u128)
                         .expect("Overflow in total_tokens_earned");
-                    env.storage().instance().set(&key, &participant);
+                    storage::save_participant(env, &participant);
                 }
 
                 // Single global-tokens update for the submitter's combined share
@@ -818,11 +946,14 @@ impl ScavengerContract {
     /// - `Recycler → Manufacturer`
     /// - `Collector → Manufacturer`
     ///
+    /// For multi-role participants the transfer is allowed if any role held
+    /// by `from` and any role held by `to` form a valid route.
+    ///
     /// # Returns
     /// `true` if both participants are registered and the role transition is allowed.
     pub fn is_valid_transfer(env: &Env, from: Address, to: Address) -> bool {
-        let from_participant: Option<Participant> = env.storage().instance().get(&(from,));
-        let to_participant: Option<Participant> = env.storage().instance().get(&(to,));
+        let from_participant = storage::get_participant(env, &from);
+        let to_participant = storage::get_participant(env, &to);
 
         let (Some(from_p), Some(to_p)) = (from_participant, to_participant) else {
             return false;
@@ -832,17 +963,18 @@ impl ScavengerContract {
             return false;
         }
 
-        // Invalid if transferring to the same role
-        if from_p.role == to_p.role {
-            return false;
-        }
-
-        matches!(
-            (from_p.role, to_p.role),
-            (ParticipantRole::Recycler, ParticipantRole::Collector)
-                | (ParticipantRole::Recycler, ParticipantRole::Manufacturer)
-                | (ParticipantRole::Collector, ParticipantRole::Manufacturer)
-        )
+        // Same-role pairs never match, so a transfer between two
+        // participants holding only the same role is rejected.
+        from_p.held_roles().any(|from_role| {
+            to_p.held_roles().any(|to_role| {
+                matches!(
+                    (from_role, to_role),
+                    (ParticipantRole::Recycler, ParticipantRole::Collector)
+                        | (ParticipantRole::Recycler, ParticipantRole::Manufacturer)
+                        | (ParticipantRole::Collector, ParticipantRole::Manufacturer)
+                )
+            })
+        })
     }
 
     /// Get the total count of waste records
@@ -907,13 +1039,13 @@ impl ScavengerContract {
     }
 
     /// Get global total weight
-    fn get_total_weight(env: &Env) -> u64 {
+    fn get_global_total_weight(env: &Env) -> u64 {
         env.storage().instance().get(&TOTAL_WEIGHT).unwrap_or(0)
     }
 
     /// Add to global total weight
     fn add_to_total_weight(env: &Env, weight: u64) {
-        let current = Self::get_total_weight(env);
+        let current = Self::get_global_total_weight(env);
         let new_total = current
             .checked_add(weight)
             .expect("Overflow in total weight");
@@ -1169,15 +1301,13 @@ impl ScavengerContract {
     /// # Returns
     /// `Some(Participant)` if the address is registered, `None` otherwise.
     pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
-        let key = (address,);
-        env.storage().instance().get(&key)
+        storage::get_participant(&env, &address)
     }
 
     /// Get total tokens earned by a specific participant
     /// Returns the total tokens earned, or 0 for unregistered participants
     pub fn get_participant_earnings(env: Env, address: Address) -> i128 {
-        let key = (address,);
-        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
+        if let Some(participant) = storage::get_participant(&env, &address) {
             participant.total_tokens_earned as i128
         } else {
             0
@@ -1213,7 +1343,7 @@ impl ScavengerContract {
         let participant_index: Vec<Address> = env
             .storage()
             .instance()
-            .get(&PART_INDEX)
+            .get(&storage::PART_INDEX)
             .unwrap_or(Vec::new(&env));
 
         let total_count = participant_index.len();
@@ -1237,11 +1367,50 @@ impl ScavengerContract {
         result
     }
 
+    /// List registered, active participants holding `role`, in registration order.
+    ///
+    /// # Parameters
+    /// - `start`: Index of the first record to return (0-based).
+    /// - `limit`: Maximum number of records to return, capped at 50.
+    ///
+    /// # Returns
+    /// Up to `limit` participants starting at `start`. Empty if `start` is
+    /// past the end of the list.
+    pub fn list_participants(env: Env, role: ParticipantRole, start: u32, limit: u32) -> Vec<Participant> {
+        let index = storage::role_index(&env, role);
+        let end = core::cmp::min(start.saturating_add(limit.min(MAX_PAGE_SIZE)), index.len());
+
+        let mut result = Vec::new(&env);
+        for i in start..end {
+            if let Some(participant) = index
+                .get(i)
+                .and_then(|addr| storage::get_participant(&env, &addr))
+            {
+                result.push_back(participant);
+            }
+        }
+        result
+    }
+
+    /// Number of registered, active participants holding `role`.
+    pub fn count_participants(env: Env, role: ParticipantRole) -> u32 {
+        storage::role_count(&env, role)
+    }
+
+    /// Number of participants ever registered, less those since removed with
+    /// [`Self::remove_participant`]. Deactivated and deregistered
+    /// participants still count.
+    pub fn total_participants(env: Env) -> u32 {
+        storage::participant_count(&env)
+    }
+
     /// Update participant role
     /// Preserves registration timestamp and other data
     /// Change the role of a registered participant.
     ///
-    /// All other fields (name, location, stats, timestamps) are preserved.
+    /// Replaces the whole role set with `new_role`; use [`add_role`] to hold
+    /// several. All other fields (name, location, stats, timestamps) are
+    /// preserved.
     ///
     /// # Parameters
     /// - `address`: Participant's address. Must sign.
@@ -1265,12 +1434,162 @@ impl ScavengerContract {
             panic!("Participant is not registered");
         }
 
-        participant.role = new_role;
-        Self::set_participant(&env, &address, &participant);
-
+        Self::replace_roles(&env, &mut participant, new_role);
         participant
     }
 
+    /// Ask the admin to switch the caller to `role`.
+    ///
+    /// Stores the request until [`Self::approve_role`] or
+    /// [`Self::reject_role`] resolves it; a new request replaces any pending
+    /// one. Emits a `role_req` event.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn request_role(env: Env, address: Address, role: ParticipantRole) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+
+        Self::load_registered(&env, &address)?;
+        storage::set_pending_role(&env, &address, role);
+
+        events::emit_role_requested(&env, &address, role);
+        Ok(())
+    }
+
+    /// Grant `address` the role it requested (admin only).
+    ///
+    /// Like [`Self::update_role`], the requested role replaces the whole role
+    /// set. Emits a `role_ok` event.
+    ///
+    /// # Returns
+    /// The updated [`Participant`].
+    ///
+    /// # Errors
+    /// - [`Error::NoPendingRole`] if `address` has no pending request.
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn approve_role(env: Env, admin: Address, address: Address) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let role = storage::pending_role(&env, &address).ok_or(Error::NoPendingRole)?;
+        let mut participant = Self::load_registered(&env, &address)?;
+        storage::clear_pending_role(&env, &address);
+        Self::replace_roles(&env, &mut participant, role);
+
+        events::emit_role_approved(&env, &address, role);
+        Ok(participant)
+    }
+
+    /// Discard `address`'s pending role request (admin only). Emits a
+    /// `role_no` event.
+    ///
+    /// # Errors
+    /// - [`Error::NoPendingRole`] if `address` has no pending request.
+    pub fn reject_role(env: Env, admin: Address, address: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let role = storage::pending_role(&env, &address).ok_or(Error::NoPendingRole)?;
+        storage::clear_pending_role(&env, &address);
+
+        events::emit_role_rejected(&env, &address, role);
+        Ok(())
+    }
+
+    /// Role `address` has requested and is awaiting approval, if any.
+    pub fn get_pending_role(env: Env, address: Address) -> Option<ParticipantRole> {
+        storage::pending_role(&env, &address)
+    }
+
+    /// Make `role` the participant's only role, moving its index entries.
+    fn replace_roles(env: &Env, participant: &mut Participant, role: ParticipantRole) {
+        if participant.active {
+            storage::remove_from_role_indexes(env, participant);
+        }
+        participant.role = role;
+        participant.roles = role.bit();
+        if participant.active {
+            storage::add_to_role_indexes(env, participant);
+        }
+        storage::save_participant(env, participant);
+    }
+
+    /// Grant an additional role to a registered participant.
+    ///
+    /// The participant is added to that role's index (if active). Granting a
+    /// role already held is a no-op.
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    /// - `role`: Role to add.
+    ///
+    /// # Returns
+    /// The updated [`Participant`].
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn add_role(env: Env, address: Address, role: ParticipantRole) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+
+        let mut participant = Self::load_registered(&env, &address)?;
+        if participant.has_role(role) {
+            return Ok(participant);
+        }
+        participant.roles |= role.bit();
+        storage::save_participant(&env, &participant);
+        if participant.active {
+            storage::add_to_role_index(&env, role, &address);
+        }
+
+        Ok(participant)
+    }
+
+    /// Drop one of a participant's roles.
+    ///
+    /// At least one role must remain. If the primary `role` is dropped, the
+    /// lowest-numbered remaining role becomes primary. Dropping a role not
+    /// held is a no-op.
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    /// - `role`: Role to remove.
+    ///
+    /// # Returns
+    /// The updated [`Participant`].
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    /// - [`Error::LastRole`] if `role` is the only role held.
+    pub fn remove_role(env: Env, address: Address, role: ParticipantRole) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+
+        let mut participant = Self::load_registered(&env, &address)?;
+        if !participant.has_role(role) {
+            return Ok(participant);
+        }
+        if participant.roles == role.bit() {
+            return Err(Error::LastRole);
+        }
+        participant.roles &= !role.bit();
+        if participant.role == role {
+            participant.role = participant
+                .held_roles()
+                .next()
+                .ok_or(Error::LastRole)?;
+        }
+        storage::save_participant(&env, &participant);
+        storage::remove_from_role_index(&env, role, &address);
+
+        Ok(participant)
+    }
+
     /// Mark a participant as deregistered (`is_registered = false`).
     ///
     /// The record is retained in storage; the participant can no longer
@@ -1288,89 +1607,537 @@ impl ScavengerContract {
         Self::require_not_paused(&env);
         address.require_auth();
 
-        let key = (address.clone(),);
-        let mut participant: Participant = env
-            .storage()
-            .instance()
-            .get(&key)
-            .expect("Participant not found");
+        let mut participant: Participant =
+            storage::get_participant(&env, &address).expect("Participant not found");
 
         participant.is_registered = false;
-        env.storage().instance().set(&key, &participant);
+        storage::save_participant(&env, &participant);
+        storage::remove_from_role_indexes(&env, &participant);
+
+        Self::remove_from_participant_index(&env, &address);
+
+        participant
+    }
+
+    /// Delete a participant outright (admin only).
+    ///
+    /// Intended for fraudulent accounts. The record is erased, the address
+    /// is dropped from every index, and any material batches it still holds
+    /// are reassigned to `admin` as custodian, with a custody entry recorded
+    /// for each. Its batch-creation totals (see [`get_total_weight`]) are
+    /// zeroed so a fraudulent account's claimed output is no longer reported;
+    /// the batches themselves keep their original custody history. Because the
+    /// record is gone the address could register again, starting from zero.
+    /// Emits a `removed` event.
+    ///
+    /// # Parameters
+    /// - `admin`: Calling admin; becomes custodian of orphaned batches. Must sign.
+    /// - `address`: Participant to remove.
+    ///
+    /// # Errors
+    /// - [`Error::NotRegistered`] if `address` has no record.
+    pub fn remove_participant(env: Env, admin: Address, address: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+
+        let participant = storage::get_participant(&env, &address).ok_or(Error::NotRegistered)?;
+
+        for batch_id in storage::owned_batches(&env, &address).iter() {
+            if let Some(mut batch) = storage::get_batch(&env, batch_id) {
+                Self::assign_custody(&env, &mut batch, &admin);
+            }
+        }
+
+        storage::decrement_participant_count(&env);
+        storage::remove_from_role_indexes(&env, &participant);
+        Self::remove_from_participant_index(&env, &address);
+        storage::remove_participant(&env, &address);
+        storage::release_name(&env, &participant.name, &address);
+        storage::remove_contact(&env, &address);
+        storage::clear_producer_totals(&env, &address);
+        storage::clear_pending_role(&env, &address);
+        leaderboard::remove(&env, &address);
+
+        events::emit_participant_removed(&env, &address, &admin);
+
+        Ok(())
+    }
 
-        // Remove from participant index
+    fn remove_from_participant_index(env: &Env, address: &Address) {
         let participant_index: Vec<Address> = env
             .storage()
             .instance()
-            .get(&PART_INDEX)
-            .unwrap_or(Vec::new(&env));
-        
-        let mut new_index = Vec::new(&env);
+            .get(&storage::PART_INDEX)
+            .unwrap_or(Vec::new(env));
+
+        let mut new_index = Vec::new(env);
         for addr in participant_index.iter() {
-            if addr != address {
+            if addr != *address {
                 new_index.push_back(addr);
             }
         }
-        env.storage().instance().set(&PART_INDEX, &new_index);
-
-        participant
+        env.storage().instance().set(&storage::PART_INDEX, &new_index);
     }
 
-    /// Update participant location
-    /// Update the location of a registered participant.
-    /// Only the participant themselves can call this.
-    /// Coordinates are scaled by 1e6 (e.g. 40_000_000 = 40.000000°).
-    pub fn update_participant_location(
-        env: Env,
-        address: Address,
-        latitude: i128,
-        longitude: i128,
-    ) -> Participant {
+    /// Temporarily opt out of the supply chain (`active = false`).
+    ///
+    /// Unlike [`deregister_participant`], the participant stays registered and
+    /// listed in [`get_all_participants`]; they drop out of the per-role index
+    /// and cannot send or receive material until they call
+    /// [`reactivate_participant`]. Emits a `deact` event.
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    ///
+    /// # Returns
+    /// The updated [`Participant`] with `active = false`.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn deactivate_participant(env: Env, address: Address) -> Result<Participant, Error> {
         Self::require_not_paused(&env);
         address.require_auth();
 
-        validation::validate_coordinates(latitude, longitude);
+        let mut participant = Self::load_registered(&env, &address)?;
+        participant.active = false;
+        storage::save_participant(&env, &participant);
+        storage::remove_from_role_indexes(&env, &participant);
 
-        let key = (address.clone(),);
-        let mut participant: Participant = env
-            .storage()
-            .instance()
-            .get(&key)
-            .expect("Participant not found");
+        events::emit_participant_deactivated(&env, &address);
 
-        if !participant.is_registered {
-            panic!("Participant is not registered");
-        }
+        Ok(participant)
+    }
 
-        participant.latitude = latitude;
-        participant.longitude = longitude;
-        env.storage().instance().set(&key, &participant);
+    /// Re-enable a participant previously deactivated with
+    /// [`deactivate_participant`].
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    ///
+    /// # Returns
+    /// The updated [`Participant`] with `active = true`.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn reactivate_participant(env: Env, address: Address) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
 
-        events::emit_participant_location_updated(&env, &address, latitude, longitude);
+        let mut participant = Self::load_registered(&env, &address)?;
+        participant.active = true;
+        storage::save_participant(&env, &participant);
+        storage::add_to_role_indexes(&env, &participant);
 
-        participant
+        Ok(participant)
     }
 
-    /// Update participant location.
+    /// Change the caller's display name.
     ///
-    /// # Deprecated
-    /// Use [`update_participant_location`] instead. That function also validates
-    /// coordinates and emits a `ParticipantLocationUpdated` event.
-    pub fn update_location(
+    /// Applies the same validation as [`register_participant`]. Emits a
+    /// `name_upd` event.
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    /// - `name`: New display name (1–32 characters).
+    ///
+    /// # Returns
+    /// The updated [`Participant`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidName`] if `name` is empty.
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    /// - [`Error::NameTaken`] if unique names are enforced and `name` is held
+    ///   by someone else.
+    pub fn update_name(
         env: Env,
         address: Address,
-        latitude: i128,
-        longitude: i128,
-    ) -> Participant {
-        Self::update_participant_location(env, address, latitude, longitude)
+        name: soroban_sdk::Symbol,
+    ) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+        validation::check_name(&env, &name)?;
+
+        let mut participant = Self::load_registered(&env, &address)?;
+        if participant.name != name {
+            storage::claim_name(&env, &name, &address)?;
+            storage::release_name(&env, &participant.name, &address);
+        }
+        participant.name = name;
+        storage::save_participant(&env, &participant);
+
+        events::emit_participant_name_updated(&env, &address, &participant.name);
+
+        Ok(participant)
     }
 
-    // ========== Waste Transfer History Functions ==========
+    /// Look up the participant holding a display name.
+    ///
+    /// Every registration and rename records its name here unless another
+    /// participant already holds it. While unique names are not enforced,
+    /// that means a shared name resolves to its earliest holder.
+    pub fn resolve_name(env: Env, name: soroban_sdk::Symbol) -> Option<Address> {
+        storage::name_owner(&env, &name)
+    }
 
-    /// Get the full transfer history for a waste item (v1 storage).
+    /// Require new registrations and renames to use a name nobody else holds
+    /// (admin only).
     ///
-    /// Checks v2 storage first (`transfer_history` key with `u128` waste ID),
-    /// then falls back to v1 (`transfers` key with `u64` waste ID).
+    /// Disabled by default. Names shared before the flag was turned on stay
+    /// shared.
+    pub fn set_enforce_unique_names(env: Env, admin: Address, enabled: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage()
+            .instance()
+            .set(&storage::DataKey::UniqueNames, &enabled);
+    }
+
+    /// Whether display names must be unique.
+    pub fn enforce_unique_names(env: Env) -> bool {
+        storage::unique_names(&env)
+    }
+
+    /// Publish how other participants can reach the caller.
+    ///
+    /// Stored apart from the [`Participant`] record so ordinary participant
+    /// reads stay cheap. Contact details identify a person, so unlike other
+    /// profile changes this emits no event, keeping handles out of the event
+    /// streams indexers archive. The stored value is still public ledger
+    /// state, readable by anyone.
+    ///
+    /// # Parameters
+    /// - `address`: Participant's address. Must sign.
+    /// - `handle`: Phone number, email address or URL (1–128 bytes).
+    /// - `kind`: What `handle` is.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidHandle`] if `handle` is empty or over 128 bytes.
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    pub fn set_contact(
+        env: Env,
+        address: Address,
+        handle: String,
+        kind: ContactKind,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+        validation::check_contact_handle(&handle)?;
+        Self::load_registered(&env, &address)?;
+
+        storage::set_contact(&env, &address, &ContactInfo { handle, kind });
+        Ok(())
+    }
+
+    /// Remove the caller's published contact details, if any. Emits no event.
+    pub fn clear_contact(env: Env, address: Address) {
+        Self::require_not_paused(&env);
+        address.require_auth();
+        storage::remove_contact(&env, &address);
+    }
+
+    /// Get the contact details a participant has published, if any.
+    pub fn get_contact(env: Env, address: Address) -> Option<ContactInfo> {
+        storage::get_contact(&env, &address)
+    }
+
+    /// Load a participant that must exist and still be registered.
+    fn load_registered(env: &Env, address: &Address) -> Result<Participant, Error> {
+        let participant =
+            storage::get_participant(env, address).ok_or(Error::ParticipantNotFound)?;
+        if !participant.is_registered {
+            return Err(Error::NotRegistered);
+        }
+        Ok(participant)
+    }
+
+    /// Great-circle distance in metres between two participants' recorded
+    /// locations.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if either address has no record.
+    pub fn distance_between(env: Env, a: Address, b: Address) -> Result<u64, Error> {
+        let a = storage::get_participant(&env, &a).ok_or(Error::ParticipantNotFound)?;
+        let b = storage::get_participant(&env, &b).ok_or(Error::ParticipantNotFound)?;
+        // Coordinates are range-checked on write, so they always fit in i64.
+        Ok(geo::distance_meters(
+            a.latitude as i64,
+            a.longitude as i64,
+            b.latitude as i64,
+            b.longitude as i64,
+        ))
+    }
+
+    /// Encode a participant's recorded location as a geohash of `precision`
+    /// characters, for bucketing by proximity off-chain.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPrecision`] if `precision` is not between 1 and 10.
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    pub fn geohash(env: Env, address: Address, precision: u32) -> Result<String, Error> {
+        if !(1..=geo::GEOHASH_MAX_PRECISION).contains(&precision) {
+            return Err(Error::InvalidPrecision);
+        }
+        let participant =
+            storage::get_participant(&env, &address).ok_or(Error::ParticipantNotFound)?;
+        let mut buf = [0u8; geo::GEOHASH_MAX_PRECISION as usize];
+        let hash = &mut buf[..precision as usize];
+        geo::geohash(participant.latitude as i64, participant.longitude as i64, hash);
+        Ok(String::from_bytes(&env, hash))
+    }
+
+    /// Find the closest active collector to `recycler`.
+    ///
+    /// Scans the collector role index, so cost grows linearly with the number
+    /// of active collectors. Ties go to the collector that registered first.
+    ///
+    /// # Returns
+    /// `None` if there is no active collector.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if `recycler` has no record.
+    pub fn nearest_collector(env: Env, recycler: Address) -> Result<Option<Address>, Error> {
+        let origin = storage::get_participant(&env, &recycler).ok_or(Error::ParticipantNotFound)?;
+
+        let mut nearest: Option<(Address, u64)> = None;
+        for candidate in storage::role_index(&env, ParticipantRole::Collector).iter() {
+            if candidate == recycler {
+                continue;
+            }
+            let Some(collector) = storage::get_participant(&env, &candidate) else {
+                continue;
+            };
+            let d = geo::distance_meters(
+                origin.latitude as i64,
+                origin.longitude as i64,
+                collector.latitude as i64,
+                collector.longitude as i64,
+            );
+            if nearest.as_ref().is_none_or(|(_, best)| d < *best) {
+                nearest = Some((candidate, d));
+            }
+        }
+
+        Ok(nearest.map(|(address, _)| address))
+    }
+
+    /// Find the most reputable active collector within `max_distance_meters`
+    /// of `recycler`.
+    ///
+    /// Candidates are ranked by, in order of precedence:
+    /// 1. highest [`Participant::reputation`],
+    /// 2. smallest distance to `recycler`,
+    /// 3. earliest registration, i.e. position in the collector role index.
+    ///
+    /// Like [`Self::nearest_collector`], this scans the collector role index.
+    ///
+    /// # Returns
+    /// `None` if no active collector is within range.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if `recycler` has no record.
+    pub fn best_collector(
+        env: Env,
+        recycler: Address,
+        max_distance_meters: u64,
+    ) -> Result<Option<Address>, Error> {
+        let origin = storage::get_participant(&env, &recycler).ok_or(Error::ParticipantNotFound)?;
+
+        let mut best: Option<(Address, i32, u64)> = None;
+        for candidate in storage::role_index(&env, ParticipantRole::Collector).iter() {
+            if candidate == recycler {
+                continue;
+            }
+            let Some(collector) = storage::get_participant(&env, &candidate) else {
+                continue;
+            };
+            let d = geo::distance_meters(
+                origin.latitude as i64,
+                origin.longitude as i64,
+                collector.latitude as i64,
+                collector.longitude as i64,
+            );
+            if d > max_distance_meters {
+                continue;
+            }
+            let better = best.as_ref().is_none_or(|(_, reputation, distance)| {
+                collector.reputation > *reputation
+                    || (collector.reputation == *reputation && d < *distance)
+            });
+            if better {
+                best = Some((candidate, collector.reputation, d));
+            }
+        }
+
+        Ok(best.map(|(address, _, _)| address))
+    }
+
+    /// Active participants whose location lies inside a bounding box.
+    ///
+    /// Bounds are inclusive microdegrees. A box with `min_lon > max_lon`
+    /// wraps across the antimeridian and matches `lon >= min_lon` or
+    /// `lon <= max_lon`. With `role` set only that role's index is scanned;
+    /// otherwise every registered participant is. Cost grows linearly with
+    /// the scanned index.
+    ///
+    /// # Returns
+    /// Up to 100 addresses, in index order.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidCoordinates`] if any bound is out of range or
+    ///   `min_lat > max_lat`.
+    pub fn participants_in_bbox(
+        env: Env,
+        min_lat: i128,
+        min_lon: i128,
+        max_lat: i128,
+        max_lon: i128,
+        role: Option<ParticipantRole>,
+    ) -> Result<Vec<Address>, Error> {
+        validation::check_coordinates(min_lat, min_lon)?;
+        validation::check_coordinates(max_lat, max_lon)?;
+        if min_lat > max_lat {
+            return Err(Error::InvalidCoordinates);
+        }
+
+        let index: Vec<Address> = match role {
+            Some(role) => storage::role_index(&env, role),
+            None => env
+                .storage()
+                .instance()
+                .get(&storage::PART_INDEX)
+                .unwrap_or(Vec::new(&env)),
+        };
+        let in_lon_range = |lon: i128| {
+            if min_lon <= max_lon {
+                (min_lon..=max_lon).contains(&lon)
+            } else {
+                lon >= min_lon || lon <= max_lon
+            }
+        };
+
+        let mut result = Vec::new(&env);
+        for address in index.iter() {
+            if result.len() >= MAX_BBOX_RESULTS {
+                break;
+            }
+            let Some(p) = storage::get_participant(&env, &address) else {
+                continue;
+            };
+            if p.is_registered
+                && p.active
+                && (min_lat..=max_lat).contains(&p.latitude)
+                && in_lon_range(p.longitude)
+            {
+                result.push_back(address);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Update participant location
+    /// Update the location of a registered participant without an oracle
+    /// proof; same as [`Self::update_location`] with `proof` set to `None`.
+    /// Coordinates are scaled by 1e6 (e.g. 40_000_000 = 40.000000°).
+    pub fn update_participant_location(
+        env: Env,
+        address: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> Result<Participant, Error> {
+        Self::update_location(env, address, latitude, longitude, None)
+    }
+
+    /// Set the ed25519 public key of the location oracle (admin only).
+    ///
+    /// The oracle vouches for participant coordinates off-chain; proofs it
+    /// signs are accepted by [`Self::update_location`].
+    pub fn set_location_oracle(env: Env, admin: Address, oracle: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+        env.storage()
+            .instance()
+            .set(&storage::DataKey::LocationOracle, &oracle);
+    }
+
+    /// Get the location oracle's public key, if one is set.
+    pub fn get_location_oracle(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&storage::DataKey::LocationOracle)
+    }
+
+    /// Whether `address`'s current location was vouched for by the oracle.
+    pub fn is_location_verified(env: Env, address: Address) -> bool {
+        storage::get_participant(&env, &address).is_some_and(|p| p.location_verified)
+    }
+
+    /// Update the location of a registered participant.
+    ///
+    /// Only the participant themselves can call this. Coordinates are scaled
+    /// by 1e6 (e.g. 40_000_000 = 40.000000°). With a `proof` from the
+    /// location oracle the participant is marked
+    /// [`Participant::location_verified`]; without one the mark is cleared,
+    /// since the oracle has not vouched for the new coordinates. The proof's
+    /// `signature` must be the oracle's ed25519 signature over the XDR
+    /// encoding of the tuple `(address, latitude, longitude, timestamp)`, and
+    /// `timestamp` must be no more than five minutes old. Emits a `loc_upd`
+    /// event carrying the coordinates and whether they are verified.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidCoordinates`] if latitude or longitude is out of range.
+    /// - [`Error::ParticipantNotFound`] if the address has no record.
+    /// - [`Error::NotRegistered`] if the participant has been deregistered.
+    /// - [`Error::LocationOracleNotSet`] if a proof is given but no oracle key
+    ///   is configured.
+    /// - [`Error::StaleLocationProof`] if the proof's `timestamp` is in the
+    ///   future or too old.
+    /// - Traps if the proof's `signature` does not verify.
+    pub fn update_location(
+        env: Env,
+        address: Address,
+        latitude: i128,
+        longitude: i128,
+        proof: Option<LocationProof>,
+    ) -> Result<Participant, Error> {
+        Self::require_not_paused(&env);
+        address.require_auth();
+
+        validation::check_coordinates(latitude, longitude)?;
+        let mut participant = Self::load_registered(&env, &address)?;
+
+        let verified = match proof {
+            Some(proof) => {
+                let oracle: BytesN<32> = env
+                    .storage()
+                    .instance()
+                    .get(&storage::DataKey::LocationOracle)
+                    .ok_or(Error::LocationOracleNotSet)?;
+                let now = env.ledger().timestamp();
+                if proof.timestamp > now || now - proof.timestamp > LOCATION_PROOF_MAX_AGE {
+                    return Err(Error::StaleLocationProof);
+                }
+                let message = (address.clone(), latitude, longitude, proof.timestamp).to_xdr(&env);
+                env.crypto().ed25519_verify(&oracle, &message, &proof.signature);
+                true
+            }
+            None => false,
+        };
+
+        participant.latitude = latitude;
+        participant.longitude = longitude;
+        participant.location_verified = verified;
+        storage::save_participant(&env, &participant);
+
+        events::emit_participant_location_updated(&env, &address, latitude, longitude, verified);
+
+        Ok(participant)
+    }
+
+    // ========== Waste Transfer History Functions ==========
+
+    /// Get the full transfer history for a waste item (v1 storage).
+    ///
+    /// Checks v2 storage first (`transfer_history` key with `u128` waste ID),
+    /// then falls back to v1 (`transfers` key with `u64` waste ID).
     ///
     /// # Returns
     /// Chronologically ordered `Vec<WasteTransfer>`. Empty if no transfers recorded.
@@ -1504,9 +2271,8 @@ impl ScavengerContract {
     /// `true` if the participant is registered and their role allows collection
     /// (`Recycler` or `Collector`).
     pub fn can_collect(env: Env, address: Address) -> bool {
-        let key = (address,);
-        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
-            participant.is_registered && participant.role.can_collect_materials()
+        if let Some(participant) = storage::get_participant(&env, &address) {
+            participant.is_registered && participant.held_roles().any(|r| r.can_collect_materials())
         } else {
             false
         }
@@ -1517,9 +2283,8 @@ impl ScavengerContract {
     /// # Returns
     /// `true` if the participant is registered and their role is `Manufacturer`.
     pub fn can_manufacture(env: Env, address: Address) -> bool {
-        let key = (address,);
-        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
-            participant.is_registered && participant.role.can_manufacture()
+        if let Some(participant) = storage::get_participant(&env, &address) {
+            participant.is_registered && participant.held_roles().any(|r| r.can_manufacture())
         } else {
             false
         }
@@ -1741,6 +2506,8 @@ impl ScavengerContract {
 
         Self::require_registered(&env, &from);
         Self::require_registered(&env, &to);
+        storage::require_active(&env, &from)?;
+        storage::require_active(&env, &to)?;
 
         if !waste.is_active {
             return Err(Error::WasteDeactivated);
@@ -1823,6 +2590,7 @@ impl ScavengerContract {
         // Validate recipient is registered
         Self::require_not_paused(&env);
         Self::require_registered(&env, &to);
+        storage::require_active(&env, &to)?;
 
         // Handle empty batch
         if waste_ids.is_empty() {
@@ -1854,6 +2622,7 @@ impl ScavengerContract {
             // Verify caller owns the waste
             Self::only_waste_owner(&env, &from, waste_id);
             Self::require_registered(&env, &from);
+            storage::require_active(&env, &from)?;
 
             // Validate transfer route
             if !Self::is_valid_transfer(&env, from.clone(), to.clone()) {
@@ -1972,25 +2741,17 @@ impl ScavengerContract {
         Self::require_registered(&env, &collector);
         Self::require_registered(&env, &manufacturer);
 
-        let collector_key = (collector.clone(),);
-        let collector_participant: Participant = env
-            .storage()
-            .instance()
-            .get(&collector_key)
-            .expect("Collector not registered");
+        let collector_participant: Participant =
+            storage::get_participant(&env, &collector).expect("Collector not registered");
 
-        if collector_participant.role != ParticipantRole::Collector {
+        if !collector_participant.has_role(ParticipantRole::Collector) {
             panic!("Only collectors can use this");
         }
 
-        let manufacturer_key = (manufacturer.clone(),);
-        let manufacturer_participant: Participant = env
-            .storage()
-            .instance()
-            .get(&manufacturer_key)
-            .expect("Manufacturer not registered");
+        let manufacturer_participant: Participant =
+            storage::get_participant(&env, &manufacturer).expect("Manufacturer not registered");
 
-        if manufacturer_participant.role != ParticipantRole::Manufacturer {
+        if !manufacturer_participant.has_role(ParticipantRole::Manufacturer) {
             panic!("Recipient must be manufacturer");
         }
 
@@ -2363,18 +3124,14 @@ impl ScavengerContract {
         verifier.require_auth();
 
         // Check if verifier is a recycler and is registered
-        let verifier_key = (verifier.clone(),);
-        let participant: Participant = env
-            .storage()
-            .instance()
-            .get(&verifier_key)
-            .expect("Verifier not registered");
+        let participant: Participant =
+            storage::get_participant(&env, &verifier).expect("Verifier not registered");
 
         if !participant.is_registered {
             panic!("Verifier is not registered");
         }
 
-        if !participant.role.can_process_recyclables() {
+        if !participant.has_role(ParticipantRole::Recycler) {
             panic!("Only recyclers can verify materials");
         }
 
@@ -2429,18 +3186,14 @@ impl ScavengerContract {
         verifier.require_auth();
 
         // Check if verifier is a recycler and is registered
-        let verifier_key = (verifier.clone(),);
-        let participant: Participant = env
-            .storage()
-            .instance()
-            .get(&verifier_key)
-            .expect("Verifier not registered");
+        let participant: Participant =
+            storage::get_participant(&env, &verifier).expect("Verifier not registered");
 
         if !participant.is_registered {
             panic!("Verifier is not registered");
         }
 
-        if !participant.role.can_process_recyclables() {
+        if !participant.has_role(ParticipantRole::Recycler) {
             panic!("Only recyclers can verify materials");
         }
 
@@ -2758,8 +3511,30 @@ impl ScavengerContract {
         env.storage().instance().get::<_, bool>(&PAUSED).unwrap_or(false)
     }
 
+    /// Replace the contract's code with previously uploaded Wasm (admin only).
+    ///
+    /// Storage is kept as-is and the new code takes effect once this
+    /// invocation returns. Whether the new code can read the existing storage
+    /// layout is the admin's responsibility; see [`crate::migrate`] for how
+    /// participant records are versioned. Works while paused, so a fix can be
+    /// shipped during an incident. Emits an `upgrade` event.
+    ///
+    /// # Errors
+    /// - Panics `"Admin not set"` if the contract has not been initialized.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+        events::emit_contract_upgraded(&env, &admin, &new_wasm_hash);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Abort with [`Error::Paused`] while the contract is paused.
+    ///
+    /// Called at the top of every state-changing entry point; read-only
+    /// functions skip it so clients can still inspect state during an incident.
     fn require_not_paused(env: &Env) {
-        assert!(!env.storage().instance().get::<_, bool>(&PAUSED).unwrap_or(false), "Contract is paused");
+        if env.storage().instance().get::<_, bool>(&PAUSED).unwrap_or(false) {
+            panic_with_error!(env, Error::Paused);
+        }
     }
 
     // ========== Incentive-Based Reward Distribution ==========
@@ -2811,9 +3586,8 @@ impl ScavengerContract {
         let mut total_distributed: i128 = 0;
 
         for transfer in transfers.iter() {
-            let key = (transfer.to.clone(),);
-            if let Some(p) = env.storage().instance().get::<_, Participant>(&key) {
-                if p.role.can_collect_materials() && !p.role.can_manufacture() {
+            if let Some(p) = storage::get_participant(&env, &transfer.to) {
+                if p.held_roles().any(|r| r.can_collect_materials() && !r.can_manufacture()) {
                     token_client.transfer(&manufacturer, &transfer.to, &collector_share);
                     Self::update_participant_stats(&env, &transfer.to, 0, collector_share as u64);
                     events::emit_tokens_rewarded(&env, &transfer.to, collector_share as u128, waste_id);
@@ -2843,4 +3617,1108 @@ impl ScavengerContract {
 
         total_reward
     }
+
+    // ========== Material Batches ==========
+
+    /// Create a new material batch owned by `recycler`.
+    ///
+    /// Ids are assigned sequentially from 1. Emits a `mat_new` event and
+    /// awards the recycler `weight_grams * rate / divisor` reward points (see
+    /// [`set_points_divisor`] and [`Self::current_reward_rate`]).
+    ///
+    /// # Parameters
+    /// - `recycler`: Creator and initial owner. Must sign.
+    /// - `kind`: Kind of material in the batch.
+    /// - `weight_grams`: Batch weight in grams.
+    ///
+    /// # Returns
+    /// The new batch id.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidWeight`] if `weight_grams` is zero.
+    /// - [`Error::ParticipantNotFound`] / [`Error::NotRegistered`] /
+    ///   [`Error::Inactive`] if `recycler` cannot currently move material.
+    /// - [`Error::NotRecycler`] if `recycler` does not have the `Recycler` role.
+    pub fn create_material_batch(
+        env: Env,
+        recycler: Address,
+        kind: MaterialKind,
+        weight_grams: u64,
+    ) -> Result<u64, Error> {
+        Self::new_batch(&env, recycler, kind, weight_grams, None)
+    }
+
+    /// Create a material batch that expires at `expires_at`.
+    ///
+    /// Behaves like [`Self::create_material_batch`], but once the ledger
+    /// timestamp reaches `expires_at` the batch can no longer be transferred
+    /// or advanced, and the admin may [`Self::purge_expired`] it.
+    ///
+    /// # Errors
+    /// - [`Error::BatchExpired`] if `expires_at` is not in the future.
+    /// - Any error returned by [`Self::create_material_batch`].
+    pub fn create_perishable_batch(
+        env: Env,
+        recycler: Address,
+        kind: MaterialKind,
+        weight_grams: u64,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Error::BatchExpired);
+        }
+        Self::new_batch(&env, recycler, kind, weight_grams, Some(expires_at))
+    }
+
+    /// Whether a batch has passed its expiry time. Batches without an
+    /// expiry, and unknown ids, never expire.
+    pub fn is_expired(env: Env, batch_id: u64) -> bool {
+        storage::get_batch(&env, batch_id)
+            .is_some_and(|batch| batch.is_expired_at(env.ledger().timestamp()))
+    }
+
+    /// Delete expired batches to reclaim storage (admin only).
+    ///
+    /// Each listed batch that exists and has expired is removed together
+    /// with its custody chain, and an `expired` event is emitted for it.
+    /// Other ids are skipped. A pending escrow offer is left in place so the
+    /// buyer can still cancel it.
+    ///
+    /// # Returns
+    /// The number of batches purged.
+    pub fn purge_expired(env: Env, admin: Address, ids: Vec<u64>) -> u32 {
+        Self::require_admin(&env, &admin);
+
+        let now = env.ledger().timestamp();
+        let mut purged = 0;
+        for id in ids.iter() {
+            let Some(batch) = storage::get_batch(&env, id) else {
+                continue;
+            };
+            if !batch.is_expired_at(now) {
+                continue;
+            }
+            storage::remove_owned_batch(&env, &batch.owner, id);
+            storage::remove_batch(&env, id);
+            storage::remove_custody(&env, id);
+            storage::remove_dispute(&env, id);
+            events::emit_batch_expired(&env, id, &batch.owner);
+            purged += 1;
+        }
+        purged
+    }
+
+    /// Shared body of the batch-creating entry points.
+    fn new_batch(
+        env: &Env,
+        recycler: Address,
+        kind: MaterialKind,
+        weight_grams: u64,
+        expires_at: Option<u64>,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(env);
+        recycler.require_auth();
+
+        validation::check_weight(weight_grams)?;
+
+        storage::require_active(env, &recycler)?;
+        let participant = storage::get_participant(env, &recycler).ok_or(Error::ParticipantNotFound)?;
+        if !participant.has_role(ParticipantRole::Recycler) {
+            return Err(Error::NotRecycler);
+        }
+
+        let batch = MaterialBatch {
+            id: storage::next_batch_id(env),
+            owner: recycler.clone(),
+            kind,
+            weight_grams,
+            created_at: env.ledger().timestamp(),
+            status: BatchStatus::Collected,
+            expires_at,
+        };
+        storage::save_batch(env, &batch);
+        storage::append_custody(env, batch.id, &recycler);
+        storage::add_owned_batch(env, &recycler, batch.id);
+        let totals = storage::record_batch_created(env, &recycler, weight_grams)?;
+        storage::record_created_batch(env, &batch);
+        leaderboard::record(env, &recycler, totals.total_weight_grams);
+
+        events::emit_material_created(env, batch.id, &recycler, kind, weight_grams);
+
+        rewards::award_points(env, &recycler, rewards::points_for_weight(env, weight_grams))?;
+
+        Ok(batch.id)
+    }
+
+    /// Get a material batch by id.
+    pub fn get_material_batch(env: Env, batch_id: u64) -> Option<MaterialBatch> {
+        storage::get_batch(&env, batch_id)
+    }
+
+    /// Hand a material batch to another participant.
+    ///
+    /// The current owner must sign. When the role flow check is enabled with
+    /// [`Self::set_enforce_role_flow`], the transfer must also move material
+    /// downstream as laid out in [`crate::flow`]. Emits a `mat_xfer` event.
+    ///
+    /// # Parameters
+    /// - `batch_id`: Batch to transfer.
+    /// - `to`: New owner. Must be a registered, active participant.
+    ///
+    /// # Returns
+    /// The updated [`MaterialBatch`].
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::BatchRecycled`] if the batch has reached `Recycled`.
+    /// - [`Error::SameAddress`] if `to` already owns the batch.
+    /// - [`Error::ParticipantNotFound`] / [`Error::NotRegistered`] /
+    ///   [`Error::Inactive`] if the owner cannot currently send material or
+    ///   `to` cannot currently receive it.
+    /// - [`Error::IllegalFlow`] if the role flow check is enabled and the
+    ///   hand-over is not downstream; see [`Self::set_enforce_role_flow`].
+    pub fn transfer_material(env: Env, batch_id: u64, to: Address) -> Result<MaterialBatch, Error> {
+        Self::require_not_paused(&env);
+
+        let mut batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        let from = batch.owner.clone();
+        from.require_auth();
+
+        Self::check_batch_transfer(&env, &batch, &to)?;
+        Self::move_batch(&env, &mut batch, &to);
+
+        events::emit_material_transferred(&env, &batch, &from);
+
+        Ok(batch)
+    }
+
+    /// Advance a batch to a later processing stage.
+    ///
+    /// Only the current owner may call this. Stages move forward only
+    /// (`Collected → InTransit → Processed → Recycled`), though intermediate
+    /// stages may be skipped. A `Recycled` batch can no longer be transferred.
+    /// Reaching `Recycled` splits the batch's weight in points among the
+    /// collectors in its custody chain. Emits a `status` event.
+    ///
+    /// # Parameters
+    /// - `batch_id`: Batch to update.
+    /// - `status`: New stage.
+    ///
+    /// # Returns
+    /// The updated [`MaterialBatch`].
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::InvalidTransition`] if `status` is not after the current stage.
+    pub fn set_batch_status(env: Env, batch_id: u64, status: BatchStatus) -> Result<MaterialBatch, Error> {
+        Self::require_not_paused(&env);
+
+        let mut batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        batch.owner.require_auth();
+
+        if batch.is_expired_at(env.ledger().timestamp()) {
+            return Err(Error::BatchExpired);
+        }
+        storage::require_undisputed(&env, batch_id)?;
+        if !batch.status.can_transition_to(status) {
+            return Err(Error::InvalidTransition);
+        }
+        batch.status = status;
+        storage::save_batch(&env, &batch);
+        if status == BatchStatus::Recycled {
+            carbon::record_recycled(&env, &batch)?;
+            rewards::award_collectors(&env, &batch)?;
+        }
+
+        events::emit_batch_status_changed(&env, batch_id, status);
+
+        Ok(batch)
+    }
+
+    /// Estimated grams of CO2 saved by recycling a batch.
+    ///
+    /// Returns 0 for unknown batches and for batches not yet `Recycled`.
+    pub fn carbon_saved(env: Env, batch_id: u64) -> u64 {
+        storage::get_batch(&env, batch_id)
+            .map(|batch| carbon::batch_saved(&batch))
+            .unwrap_or(0)
+    }
+
+    /// Estimated grams of CO2 saved by every recycled batch `address` created.
+    ///
+    /// A batch counts towards the first holder in its custody chain once it
+    /// reaches `Recycled`, whoever owns it by then.
+    pub fn participant_carbon_total(env: Env, address: Address) -> u64 {
+        carbon::participant_total(&env, &address)
+    }
+
+    /// Designate the address allowed to publish material prices (admin only).
+    pub fn set_price_admin(env: Env, admin: Address, price_admin: Address) {
+        Self::require_admin(&env, &admin);
+        pricing::set_price_admin(&env, &price_admin);
+    }
+
+    /// Get the address allowed to publish material prices, if one is set.
+    pub fn get_price_admin(env: Env) -> Option<Address> {
+        pricing::price_admin(&env)
+    }
+
+    /// Publish the market price per gram of a material kind. Emits a
+    /// `price` event.
+    ///
+    /// # Parameters
+    /// - `price_admin`: The address designated by `set_price_admin`. Must sign.
+    /// - `kind`: Material kind being priced.
+    /// - `price_per_gram`: New price, in the smallest currency unit per gram.
+    ///
+    /// # Errors
+    /// - [`Error::Unauthorized`] if `price_admin` is not the designated price
+    ///   admin, or none has been designated yet.
+    /// - [`Error::InvalidAmount`] if `price_per_gram` is zero or negative.
+    pub fn set_price(
+        env: Env,
+        price_admin: Address,
+        kind: MaterialKind,
+        price_per_gram: i128,
+    ) -> Result<(), Error> {
+        if pricing::price_admin(&env).as_ref() != Some(&price_admin) {
+            return Err(Error::Unauthorized);
+        }
+        price_admin.require_auth();
+        pricing::set_price(&env, kind, price_per_gram)
+    }
+
+    /// Get the published price per gram of a material kind, if any.
+    pub fn get_price(env: Env, kind: MaterialKind) -> Option<i128> {
+        pricing::price(&env, kind)
+    }
+
+    /// Estimated market value of a batch: its weight times the current price
+    /// per gram for its kind.
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has this id.
+    /// - [`Error::NoPrice`] if no price has been published for the batch's kind.
+    /// - [`Error::Overflow`] if the value does not fit in an `i128`.
+    pub fn estimate_value(env: Env, batch_id: u64) -> Result<i128, Error> {
+        let batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        pricing::estimate(&env, &batch)
+    }
+
+    /// Split a batch into smaller batches with the given weights.
+    ///
+    /// Only the current owner may call this. The original batch is consumed:
+    /// its record is deleted (its custody chain is kept for provenance) and
+    /// one child per entry in `weights` is created with the same kind and
+    /// status. Each child starts with a copy of the parent's custody chain
+    /// plus a new entry for the owner marking the split. Emits a `split`
+    /// event.
+    ///
+    /// # Parameters
+    /// - `batch_id`: Batch to split.
+    /// - `weights`: Child weights in grams; must sum to the parent's weight.
+    ///
+    /// # Returns
+    /// The new batch ids, in the order of `weights`.
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::BatchRecycled`] if the batch has reached `Recycled`.
+    /// - [`Error::InvalidWeight`] if `weights` has fewer than two entries or
+    ///   any entry is zero.
+    /// - [`Error::WeightMismatch`] if `weights` does not sum to the parent's weight.
+    pub fn split_batch(env: Env, batch_id: u64, weights: Vec<u64>) -> Result<Vec<u64>, Error> {
+        Self::require_not_paused(&env);
+
+        let parent = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        parent.owner.require_auth();
+
+        if parent.status.is_final() {
+            return Err(Error::BatchRecycled);
+        }
+        storage::require_undisputed(&env, batch_id)?;
+        if weights.len() < 2 {
+            return Err(Error::InvalidWeight);
+        }
+        for weight_grams in weights.iter() {
+            validation::check_weight(weight_grams)?;
+        }
+        let total = weights
+            .iter()
+            .try_fold(0u64, |acc, w| acc.checked_add(w))
+            .ok_or(Error::WeightMismatch)?;
+        if total != parent.weight_grams {
+            return Err(Error::WeightMismatch);
+        }
+
+        let chain = storage::get_custody(&env, batch_id);
+        let origins = storage::has_origins(&env, batch_id).then(|| storage::batch_origins(&env, &parent));
+        let mut child_ids = Vec::new(&env);
+        for weight_grams in weights.iter() {
+            let child = MaterialBatch {
+                id: storage::next_batch_id(&env),
+                owner: parent.owner.clone(),
+                kind: parent.kind,
+                weight_grams,
+                created_at: env.ledger().timestamp(),
+                status: parent.status,
+                expires_at: parent.expires_at,
+            };
+            storage::save_batch(&env, &child);
+            storage::set_custody(&env, child.id, &chain);
+            storage::set_admin_custody(&env, child.id, &storage::admin_custody(&env, batch_id));
+            storage::append_custody(&env, child.id, &parent.owner);
+            if let Some(origins) = &origins {
+                storage::set_origins(&env, child.id, &Self::scale_origins(&env, origins, weight_grams));
+            }
+            storage::add_owned_batch(&env, &parent.owner, child.id);
+            child_ids.push_back(child.id);
+        }
+
+        storage::remove_owned_batch(&env, &parent.owner, batch_id);
+        storage::remove_batch(&env, batch_id);
+
+        events::emit_batch_split(&env, batch_id, &child_ids);
+
+        Ok(child_ids)
+    }
+
+    /// Combine several batches of the same kind into one.
+    ///
+    /// `owner` must hold every input. The inputs are deleted and a new batch
+    /// is created whose weight is their sum and whose status is the most
+    /// advanced among them. Its custody chain opens with the creation entry
+    /// of each distinct creator, oldest first, followed by a new entry for
+    /// `owner`; each creator's share of the weight is kept for carbon
+    /// credit (see [`Self::get_batch_origins`]). The consumed ids are
+    /// published in a `merged` event and their own custody chains stay
+    /// readable.
+    ///
+    /// # Parameters
+    /// - `owner`: Current owner of all inputs. Must sign.
+    /// - `batch_ids`: Batches to merge; at least two distinct ids.
+    ///
+    /// # Returns
+    /// The new batch id.
+    ///
+    /// # Errors
+    /// - [`Error::TooFewBatches`] if fewer than two distinct ids are given.
+    /// - [`Error::BatchNotFound`] if any input does not exist.
+    /// - [`Error::NotBatchOwner`] if `owner` does not hold every input.
+    /// - [`Error::BatchRecycled`] if any input has reached `Recycled`.
+    /// - [`Error::KindMismatch`] if the inputs are not all the same kind.
+    /// - [`Error::InvalidWeight`] if the inputs weigh nothing in total.
+    pub fn merge_batches(env: Env, owner: Address, batch_ids: Vec<u64>) -> Result<u64, Error> {
+        Self::require_not_paused(&env);
+        owner.require_auth();
+
+        if batch_ids.len() < 2 {
+            return Err(Error::TooFewBatches);
+        }
+
+        let mut inputs: Vec<MaterialBatch> = Vec::new(&env);
+        for (i, id) in batch_ids.iter().enumerate() {
+            if batch_ids.first_index_of(id) != Some(i as u32) {
+                return Err(Error::TooFewBatches);
+            }
+            let batch = storage::get_batch(&env, id).ok_or(Error::BatchNotFound)?;
+            if batch.owner != owner {
+                return Err(Error::NotBatchOwner);
+            }
+            if batch.status.is_final() {
+                return Err(Error::BatchRecycled);
+            }
+            storage::require_undisputed(&env, id)?;
+            inputs.push_back(batch);
+        }
+
+        let first = inputs.get_unchecked(0);
+        let mut weight_grams: u64 = 0;
+        let mut status = first.status;
+        let mut expires_at = None;
+        let mut origins: Vec<BatchOrigin> = Vec::new(&env);
+        let mut creators: Vec<CustodyEntry> = Vec::new(&env);
+        for batch in inputs.iter() {
+            if batch.kind != first.kind {
+                return Err(Error::KindMismatch);
+            }
+            weight_grams = weight_grams
+                .checked_add(batch.weight_grams)
+                .ok_or(Error::Overflow)?;
+            status = status.max(batch.status);
+            expires_at = expires_at.into_iter().chain(batch.expires_at).min();
+
+            for origin in storage::batch_origins(&env, &batch).iter() {
+                match origins.iter().position(|o| o.creator == origin.creator) {
+                    Some(i) => {
+                        let mut merged = origins.get_unchecked(i as u32);
+                        merged.weight_grams += origin.weight_grams;
+                        origins.set(i as u32, merged);
+                    }
+                    None => origins.push_back(origin),
+                }
+            }
+            if let Some(created) = storage::get_custody(&env, batch.id).first() {
+                if !creators.iter().any(|c| c.holder == created.holder) {
+                    let at = creators
+                        .iter()
+                        .position(|c| c.received_at > created.received_at)
+                        .map_or(creators.len(), |i| i as u32);
+                    creators.insert(at, created);
+                }
+            }
+        }
+        validation::check_weight(weight_grams)?;
+
+        let merged = MaterialBatch {
+            id: storage::next_batch_id(&env),
+            owner: owner.clone(),
+            kind: first.kind,
+            weight_grams,
+            created_at: env.ledger().timestamp(),
+            status,
+            expires_at,
+        };
+        storage::save_batch(&env, &merged);
+        storage::set_custody(&env, merged.id, &creators);
+        storage::append_custody(&env, merged.id, &owner);
+        if origins.len() > 1 {
+            storage::set_origins(&env, merged.id, &origins);
+        }
+        storage::add_owned_batch(&env, &owner, merged.id);
+
+        for id in batch_ids.iter() {
+            storage::remove_owned_batch(&env, &owner, id);
+            storage::remove_batch(&env, id);
+        }
+
+        events::emit_batches_merged(&env, merged.id, &batch_ids);
+
+        Ok(merged.id)
+    }
+
+    /// Each creator's share of `weight_grams`, in proportion to what they
+    /// supplied in `origins`. Rounding leftovers go to the last creator.
+    fn scale_origins(env: &Env, origins: &Vec<BatchOrigin>, weight_grams: u64) -> Vec<BatchOrigin> {
+        let supplied: u128 = origins.iter().map(|o| o.weight_grams as u128).sum();
+        let mut scaled = Vec::new(env);
+        let mut assigned = 0u64;
+        for (i, origin) in origins.iter().enumerate() {
+            let share = if i as u32 + 1 == origins.len() {
+                weight_grams - assigned
+            } else {
+                (weight_grams as u128 * origin.weight_grams as u128 / supplied) as u64
+            };
+            assigned += share;
+            scaled.push_back(BatchOrigin {
+                creator: origin.creator,
+                weight_grams: share,
+            });
+        }
+        scaled
+    }
+
+    /// Contest a batch's recorded weight or kind.
+    ///
+    /// Any address in the batch's custody chain may raise a dispute. Until
+    /// the admin calls [`Self::resolve_dispute`] the batch cannot be
+    /// transferred, split, merged or have its status advanced. Emits a
+    /// `dispute` event.
+    ///
+    /// # Parameters
+    /// - `flagger`: Past or present holder of the batch. Must sign.
+    /// - `batch_id`: Batch being disputed.
+    /// - `reason`: Free-text explanation.
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::NotCustodian`] if `flagger` never held the batch.
+    /// - [`Error::AlreadyDisputed`] if the batch already has an open dispute.
+    pub fn flag_batch(env: Env, flagger: Address, batch_id: u64, reason: String) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        flagger.require_auth();
+
+        storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        if !storage::get_custody(&env, batch_id).iter().any(|e| e.holder == flagger) {
+            return Err(Error::NotCustodian);
+        }
+        if storage::get_dispute(&env, batch_id).is_some() {
+            return Err(Error::AlreadyDisputed);
+        }
+
+        let dispute = Dispute {
+            flagger,
+            reason,
+            flagged_at: env.ledger().timestamp(),
+        };
+        storage::save_dispute(&env, batch_id, &dispute);
+
+        events::emit_batch_disputed(&env, batch_id, &dispute);
+        Ok(())
+    }
+
+    /// Close the open dispute over a batch (admin only). Emits a `resolved` event.
+    ///
+    /// # Errors
+    /// - [`Error::NotDisputed`] if the batch has no open dispute.
+    pub fn resolve_dispute(env: Env, admin: Address, batch_id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        if storage::get_dispute(&env, batch_id).is_none() {
+            return Err(Error::NotDisputed);
+        }
+        storage::remove_dispute(&env, batch_id);
+
+        events::emit_dispute_resolved(&env, batch_id, &admin);
+        Ok(())
+    }
+
+    /// Get the open dispute over a batch, if any.
+    pub fn get_dispute(env: Env, batch_id: u64) -> Option<Dispute> {
+        storage::get_dispute(&env, batch_id)
+    }
+
+    /// Lock `amount` of `token` in the contract against delivery of a batch.
+    ///
+    /// The tokens are released to whoever owns the batch when the buyer calls
+    /// [`Self::confirm_receipt`], or refunded by [`Self::cancel_offer`]. A
+    /// batch can have only one pending offer. Emits an `escrow_new` event.
+    ///
+    /// # Parameters
+    /// - `buyer`: Participant paying for the batch. Must sign.
+    /// - `batch_id`: Batch being paid for.
+    /// - `token`: Token contract to pay in.
+    /// - `amount`: Amount to lock; must be positive.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `amount` is not positive.
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::BatchRecycled`] if the batch can no longer change hands.
+    /// - [`Error::SameAddress`] if `buyer` already owns the batch.
+    /// - [`Error::EscrowExists`] if the batch already has a pending offer.
+    pub fn offer_payment(
+        env: Env,
+        buyer: Address,
+        batch_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+        buyer.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        Self::check_batch_transfer(&env, &batch, &buyer)?;
+        if storage::get_escrow(&env, batch_id).is_some() {
+            return Err(Error::EscrowExists);
+        }
+
+        let escrow = Escrow {
+            buyer: buyer.clone(),
+            token: token.clone(),
+            amount,
+            created_at: env.ledger().timestamp(),
+        };
+        storage::save_escrow(&env, batch_id, &escrow);
+
+        Self::lock(&env);
+        token::Client::new(&env, &token).transfer(&buyer, &env.current_contract_address(), &amount);
+        Self::unlock(&env);
+
+        events::emit_escrow_created(&env, batch_id, &escrow);
+        Ok(())
+    }
+
+    /// Settle a pending offer: hand the batch to the buyer and pay its owner.
+    ///
+    /// Both the buyer and the current owner must sign, so a batch cannot be
+    /// taken without its owner's consent. The transfer follows the same rules
+    /// as [`Self::transfer_material`]. Emits `mat_xfer` and `escrow_paid`.
+    ///
+    /// # Returns
+    /// The batch after the transfer.
+    ///
+    /// # Errors
+    /// - [`Error::EscrowNotFound`] if the batch has no pending offer.
+    /// - [`Error::BatchNotFound`] if the batch no longer exists; the buyer can
+    ///   still [`Self::cancel_offer`].
+    /// - Any error returned by [`Self::transfer_material`].
+    pub fn confirm_receipt(env: Env, batch_id: u64) -> Result<MaterialBatch, Error> {
+        Self::require_not_paused(&env);
+
+        let escrow = storage::get_escrow(&env, batch_id).ok_or(Error::EscrowNotFound)?;
+        escrow.buyer.require_auth();
+        let mut batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        let seller = batch.owner.clone();
+        seller.require_auth();
+
+        Self::check_batch_transfer(&env, &batch, &escrow.buyer)?;
+        Self::move_batch(&env, &mut batch, &escrow.buyer);
+        storage::remove_escrow(&env, batch_id);
+
+        Self::lock(&env);
+        token::Client::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &seller,
+            &escrow.amount,
+        );
+        Self::unlock(&env);
+
+        events::emit_material_transferred(&env, &batch, &seller);
+        events::emit_escrow_paid(&env, batch_id, &seller, escrow.amount);
+        Ok(batch)
+    }
+
+    /// Withdraw a pending offer and refund the buyer. Emits `escrow_cancel`.
+    ///
+    /// # Errors
+    /// - [`Error::EscrowNotFound`] if the batch has no pending offer.
+    pub fn cancel_offer(env: Env, batch_id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env);
+
+        let escrow = storage::get_escrow(&env, batch_id).ok_or(Error::EscrowNotFound)?;
+        escrow.buyer.require_auth();
+        storage::remove_escrow(&env, batch_id);
+
+        Self::lock(&env);
+        token::Client::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &escrow.buyer,
+            &escrow.amount,
+        );
+        Self::unlock(&env);
+
+        events::emit_escrow_cancelled(&env, batch_id, &escrow.buyer, escrow.amount);
+        Ok(())
+    }
+
+    /// Get the pending payment offer for a batch, if any.
+    pub fn get_escrow(env: Env, batch_id: u64) -> Option<Escrow> {
+        storage::get_escrow(&env, batch_id)
+    }
+
+    /// Checks shared by every path that hands `batch` to `to`.
+    fn check_batch_transfer(env: &Env, batch: &MaterialBatch, to: &Address) -> Result<(), Error> {
+        if batch.status.is_final() {
+            return Err(Error::BatchRecycled);
+        }
+        if batch.is_expired_at(env.ledger().timestamp()) {
+            return Err(Error::BatchExpired);
+        }
+        storage::require_undisputed(env, batch.id)?;
+        if batch.owner == *to {
+            return Err(Error::SameAddress);
+        }
+        storage::require_active(env, &batch.owner)?;
+        storage::require_active(env, to)?;
+
+        if flow::enforced(env) {
+            let from_p = storage::get_participant(env, &batch.owner);
+            let to_p = storage::get_participant(env, to);
+            let permitted = matches!((from_p, to_p), (Some(f), Some(t)) if flow::permits(&f, &t));
+            if !permitted {
+                return Err(Error::IllegalFlow);
+            }
+        }
+        Ok(())
+    }
+
+    /// Move a batch to a new owner without its current owner's signature
+    /// (admin only).
+    ///
+    /// A recovery tool for batches stranded by a lost key. The transfer
+    /// skips the route, role flow, expiry and dispute checks of
+    /// [`Self::transfer_material`], and records no counterparty for
+    /// [`Self::rate_participant`]. The new custody entry is listed by
+    /// [`Self::get_admin_custody`]. Emits an `admin_xfer` event.
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    /// - [`Error::BatchRecycled`] if the batch has reached `Recycled`.
+    /// - [`Error::SameAddress`] if `new_owner` already owns the batch.
+    /// - [`Error::ParticipantNotFound`] / [`Error::NotRegistered`] /
+    ///   [`Error::Inactive`] if `new_owner` cannot currently receive material.
+    pub fn reassign_batch(
+        env: Env,
+        admin: Address,
+        batch_id: u64,
+        new_owner: Address,
+    ) -> Result<MaterialBatch, Error> {
+        Self::require_admin(&env, &admin);
+
+        let mut batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        if batch.status.is_final() {
+            return Err(Error::BatchRecycled);
+        }
+        if batch.owner == new_owner {
+            return Err(Error::SameAddress);
+        }
+        storage::require_active(&env, &new_owner)?;
+
+        let from = batch.owner.clone();
+        Self::assign_custody(&env, &mut batch, &new_owner);
+        storage::mark_admin_custody(&env, batch_id);
+
+        events::emit_batch_reassigned(&env, batch_id, &admin, &from, &new_owner);
+
+        Ok(batch)
+    }
+
+    /// Positions in [`Self::get_custody_chain`] that were created by
+    /// [`Self::reassign_batch`] rather than a normal hand-over.
+    pub fn get_admin_custody(env: Env, batch_id: u64) -> Vec<u32> {
+        storage::admin_custody(&env, batch_id)
+    }
+
+    /// Hand `batch` to `to`, keeping the custody chain and owner index in step.
+    fn move_batch(env: &Env, batch: &mut MaterialBatch, to: &Address) {
+        storage::record_counterparty(env, &batch.owner, to);
+        Self::assign_custody(env, batch, to);
+    }
+
+    /// Make `to` the owner of `batch` with a custody entry, without recording
+    /// the two as counterparties. Used for admin custody.
+    fn assign_custody(env: &Env, batch: &mut MaterialBatch, to: &Address) {
+        storage::append_custody(env, batch.id, to);
+        storage::remove_owned_batch(env, &batch.owner, batch.id);
+        storage::add_owned_batch(env, to, batch.id);
+        batch.owner = to.clone();
+        storage::save_batch(env, batch);
+    }
+
+    /// Get the chain of custody for a batch, oldest first.
+    ///
+    /// The first entry is always the recycler that created the batch.
+    /// Entries are ordered by position; `received_at` never decreases along
+    /// the chain, and hand-overs within one ledger share its timestamp.
+    /// Returns an empty list for an unknown batch.
+    pub fn get_custody_chain(env: Env, batch_id: u64) -> Vec<CustodyEntry> {
+        storage::get_custody(&env, batch_id)
+    }
+
+    /// Who created the material in a batch and how many grams each supplied.
+    ///
+    /// A batch never merged has a single origin, its creator. Returns an
+    /// empty list for an unknown batch.
+    pub fn get_batch_origins(env: Env, batch_id: u64) -> Vec<BatchOrigin> {
+        match storage::get_batch(&env, batch_id) {
+            Some(batch) => storage::batch_origins(&env, &batch),
+            None => Vec::new(&env),
+        }
+    }
+
+    /// Everything recorded about a batch in one read: the record, its
+    /// custody chain, CO2 saved, estimated value and dispute state.
+    ///
+    /// Subsystems with nothing to report contribute a zero value rather than
+    /// an error, e.g. `estimated_value` is 0 when no price is published.
+    ///
+    /// # Errors
+    /// - [`Error::BatchNotFound`] if no batch has `batch_id`.
+    pub fn batch_report(env: Env, batch_id: u64) -> Result<BatchReport, Error> {
+        let batch = storage::get_batch(&env, batch_id).ok_or(Error::BatchNotFound)?;
+        Ok(BatchReport {
+            custody: storage::get_custody(&env, batch_id),
+            carbon_saved: carbon::batch_saved(&batch),
+            estimated_value: pricing::estimate(&env, &batch).unwrap_or(0),
+            disputed: storage::get_dispute(&env, batch_id).is_some(),
+            batch,
+        })
+    }
+
+    /// Grams of material `address` created in batches whose `created_at`
+    /// lies in the inclusive window `from_ts..=to_ts`.
+    ///
+    /// Batches count at their weight when created, even if they have since
+    /// been split, merged or purged. Only the address's most recent 500
+    /// batches are kept for this query, so older activity is not counted;
+    /// [`Self::get_total_weight`] has the all-time figure.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidWindow`] if `from_ts > to_ts`.
+    pub fn weight_in_window(env: Env, address: Address, from_ts: u64, to_ts: u64) -> Result<u64, Error> {
+        if from_ts > to_ts {
+            return Err(Error::InvalidWindow);
+        }
+        storage::weight_in_window(&env, &address, from_ts, to_ts)
+    }
+
+    /// Total grams of material in the batches `address` has created.
+    pub fn get_total_weight(env: Env, address: Address) -> u64 {
+        storage::producer_totals(&env, &address).total_weight_grams
+    }
+
+    /// Number of material batches `address` has created.
+    pub fn get_total_batches(env: Env, address: Address) -> u32 {
+        storage::producer_totals(&env, &address).total_batches
+    }
+
+    /// The `n` producers with the highest total batch weight, heaviest first.
+    ///
+    /// `n` is capped at 20. Equal totals are ranked by who reached the total
+    /// first.
+    pub fn top_recyclers(env: Env, n: u32) -> Vec<(Address, u64)> {
+        let board = leaderboard::entries(&env);
+        board.slice(0..n.min(board.len()))
+    }
+
+    /// Get the reward-point balance of `address`.
+    pub fn get_points(env: Env, address: Address) -> u64 {
+        rewards::get_points(&env, &address)
+    }
+
+    /// Get the number of grams of material that earns one reward point.
+    pub fn get_points_divisor(env: Env) -> u64 {
+        rewards::points_divisor(&env)
+    }
+
+    /// Get the reward halving schedule, if one has been set.
+    pub fn get_reward_schedule(env: Env) -> Option<RewardSchedule> {
+        rewards::reward_schedule(&env)
+    }
+
+    /// Start a reward halving schedule from the current ledger (admin only).
+    ///
+    /// Points earned per [`Self::get_points_divisor`] grams start at
+    /// `base_rate` and halve every `halving_interval_ledgers` ledgers, never
+    /// dropping below 1. Calling this again restarts the schedule. Without a
+    /// schedule the rate is 1.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `base_rate` or `halving_interval_ledgers`
+    ///   is zero.
+    pub fn set_reward_schedule(
+        env: Env,
+        admin: Address,
+        base_rate: u64,
+        halving_interval_ledgers: u32,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+        rewards::set_reward_schedule(&env, base_rate, halving_interval_ledgers)
+    }
+
+    /// Points currently earned per [`Self::get_points_divisor`] grams of material.
+    pub fn current_reward_rate(env: Env) -> u64 {
+        rewards::current_rate(&env)
+    }
+
+    /// Set the number of grams of material that earns one reward point (admin only).
+    ///
+    /// Defaults to 100.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `divisor` is zero.
+    pub fn set_points_divisor(env: Env, admin: Address, divisor: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+        rewards::set_points_divisor(&env, divisor)
+    }
+
+    /// Rewrite `address`'s participant record in the current layout (admin only).
+    ///
+    /// Records from older contract versions are readable without this, since
+    /// reads upgrade them in memory, but migrating persists the defaults for
+    /// new fields, moves a record the original contract kept in instance
+    /// storage under `DataKey::Participant`, and adds an active participant
+    /// to its role index.
+    ///
+    /// # Returns
+    /// The migrated record.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if `address` has no record.
+    pub fn migrate_participant(env: Env, admin: Address, address: Address) -> Result<Participant, Error> {
+        Self::require_admin(&env, &admin);
+        let participant = storage::get_participant(&env, &address).ok_or(Error::ParticipantNotFound)?;
+        storage::save_participant(&env, &participant);
+        if participant.is_registered && participant.active {
+            storage::add_to_role_indexes(&env, &participant);
+        }
+        Ok(participant)
+    }
+
+    /// Get the most registrations accepted within one ledger.
+    pub fn get_registration_limit(env: Env) -> u32 {
+        storage::registration_limit(&env)
+    }
+
+    /// Set the most registrations accepted within one ledger (admin only).
+    ///
+    /// Defaults to 200.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `limit` is zero.
+    pub fn set_registration_limit(env: Env, admin: Address, limit: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+        storage::set_registration_limit(&env, limit)
+    }
+
+    /// Extend the storage TTL of `address`'s participant record.
+    ///
+    /// Persistent entries are archived once their TTL lapses; see the
+    /// `storage` module docs. Anyone may pay to keep a record alive, and the
+    /// record itself is not modified, so this works while paused.
+    ///
+    /// # Errors
+    /// - [`Error::ParticipantNotFound`] if `address` has no record.
+    pub fn bump_participant_ttl(env: Env, address: Address) -> Result<(), Error> {
+        if storage::bump_participant(&env, &address) {
+            Ok(())
+        } else {
+            Err(Error::ParticipantNotFound)
+        }
+    }
+
+    /// Get the number of ledgers participant records are kept alive for.
+    pub fn get_ttl(env: Env) -> u32 {
+        storage::ttl_ledgers(&env)
+    }
+
+    /// Set the number of ledgers participant records are kept alive for on
+    /// each write or bump (admin only).
+    ///
+    /// Defaults to [`DEFAULT_TTL_LEDGERS`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `ledgers` is zero or above the network's
+    ///   maximum entry TTL.
+    pub fn set_ttl(env: Env, admin: Address, ledgers: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+        storage::set_ttl_ledgers(&env, ledgers)
+    }
+
+    /// Get the number of reward points redeemed for one reward token unit.
+    pub fn get_points_per_token(env: Env) -> u64 {
+        rewards::points_per_token(&env)
+    }
+
+    /// Set the number of reward points redeemed for one reward token unit
+    /// (admin only).
+    ///
+    /// Defaults to 100.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `rate` is zero.
+    pub fn set_points_per_token(env: Env, admin: Address, rate: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin);
+        rewards::set_points_per_token(&env, rate)
+    }
+
+    /// Exchange reward points for the reward token set by `set_token_address`.
+    ///
+    /// Pays `amount / points_per_token` tokens from the contract's own
+    /// balance and burns only the points that buys, so a remainder below the
+    /// rate stays in the caller's balance. Tokens locked by
+    /// [`Self::offer_payment`] are not part of the reward pool. Emits a
+    /// `redeem` event.
+    ///
+    /// # Parameters
+    /// - `caller`: Address redeeming its points. Must sign.
+    /// - `amount`: Points to redeem.
+    ///
+    /// # Returns
+    /// The number of tokens paid out.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidAmount`] if `amount` buys less than one token.
+    /// - [`Error::InsufficientPoints`] if `caller` holds fewer than `amount` points.
+    /// - [`Error::TokenAddressNotSet`] if no reward token is configured.
+    /// - [`Error::InsufficientRewardPool`] if the contract holds too few
+    ///   tokens outside of escrow.
+    pub fn redeem_points(env: Env, caller: Address, amount: u64) -> Result<i128, Error> {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        if rewards::get_points(&env, &caller) < amount {
+            return Err(Error::InsufficientPoints);
+        }
+        let rate = rewards::points_per_token(&env);
+        let tokens = amount / rate;
+        if tokens == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let burned = tokens * rate;
+
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&TOKEN_ADDR)
+            .ok_or(Error::TokenAddressNotSet)?;
+        let token_client = token::Client::new(&env, &token_address);
+        let payout = tokens as i128;
+        let pool = token_client
+            .balance(&env.current_contract_address())
+            .saturating_sub(storage::escrowed(&env, &token_address));
+        if pool < payout {
+            return Err(Error::InsufficientRewardPool);
+        }
+
+        rewards::spend_points(&env, &caller, burned)?;
+
+        Self::lock(&env);
+        token_client.transfer(&env.current_contract_address(), &caller, &payout);
+        Self::unlock(&env);
+
+        events::emit_points_redeemed(&env, &caller, burned, payout);
+        Ok(payout)
+    }
+
+    /// Adjust a counterparty's reputation.
+    ///
+    /// `rater` and `target` must have handed a material batch directly to one
+    /// another at some point. `delta` is clamped to `[-5, 5]` and the result
+    /// to `[-1000, 1000]`. Each rater may rate a given target at most once per
+    /// ledger day (UTC). Emits a `rated` event.
+    ///
+    /// # Parameters
+    /// - `rater`: Participant giving the rating. Must sign.
+    /// - `target`: Participant being rated.
+    /// - `delta`: Requested score change.
+    ///
+    /// # Returns
+    /// The target's new reputation.
+    ///
+    /// # Errors
+    /// - [`Error::SameAddress`] if `rater == target`.
+    /// - [`Error::ParticipantNotFound`] if `target` has no record.
+    /// - [`Error::NotCounterparty`] if no batch has passed between them.
+    /// - [`Error::AlreadyRated`] if `rater` already rated `target` today.
+    pub fn rate_participant(env: Env, rater: Address, target: Address, delta: i32) -> Result<i32, Error> {
+        Self::require_not_paused(&env);
+        rater.require_auth();
+
+        if rater == target {
+            return Err(Error::SameAddress);
+        }
+        let mut participant = storage::get_participant(&env, &target).ok_or(Error::ParticipantNotFound)?;
+        if !storage::are_counterparties(&env, &rater, &target) {
+            return Err(Error::NotCounterparty);
+        }
+        reputation::mark_rated_today(&env, &rater, &target)?;
+
+        participant.reputation = reputation::apply_delta(participant.reputation, delta);
+        storage::save_participant(&env, &participant);
+
+        events::emit_participant_rated(&env, &target, &rater, participant.reputation);
+
+        Ok(participant.reputation)
+    }
+
+    /// Restrict batch hand-overs to the canonical downstream flow (admin only).
+    ///
+    /// Only `Recycler → Collector`, `Collector → Collector` and
+    /// `Collector → Manufacturer` are then allowed; see [`crate::flow`] for
+    /// the full table. Disabled by default, in which case any active
+    /// participant can receive.
+    pub fn set_enforce_role_flow(env: Env, admin: Address, enabled: bool) {
+        Self::require_admin(&env, &admin);
+        flow::set_enforced(&env, enabled);
+    }
+
+    /// Whether batch hand-overs must follow the canonical role flow.
+    pub fn enforce_role_flow(env: Env) -> bool {
+        flow::enforced(&env)
+    }
 }
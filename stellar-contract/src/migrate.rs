@@ -0,0 +1,275 @@
+//! Upgrades for participant records written by older contract versions.
+//!
+//! Soroban decodes stored structs strictly, so once fields are added to
+//! [`Participant`] a record in the old layout can no longer be read as the
+//! new type. Each record therefore has a schema version stored alongside it
+//! under `DataKey::ParticipantVersion`. Records without one predate
+//! versioning and are in the [`ParticipantV1`] layout; each later layout
+//! keeps its own struct here so every version can still be decoded.
+//!
+//! V1 records written by the original contract live in instance storage
+//! under a [`legacy_key`] tuple rather than `DataKey::Participant`. Reads
+//! fall back to that entry when no persistent record exists.
+//!
+//! Reads upgrade old records in memory; the upgraded layout is persisted,
+//! and any legacy instance entry removed, the next time the record is
+//! written, or straight away via `migrate_participant`.
+
+use soroban_sdk::{contracttype, Address, Symbol};
+
+use crate::{Participant, ParticipantRole};
+
+/// Layout of the original contract's records, kept in instance storage under
+/// [`legacy_key`], from before `roles`, `active` and `reputation` were added.
+pub const PARTICIPANT_V1: u32 = 1;
+
+/// Layout of records written before `location_verified` was added.
+pub const PARTICIPANT_V2: u32 = 2;
+
+/// Layout of [`Participant`] as currently defined.
+pub const PARTICIPANT_SCHEMA_VERSION: u32 = 3;
+
+/// Participant record as stored by schema version 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParticipantV1 {
+    pub address: Address,
+    pub role: ParticipantRole,
+    pub name: Symbol,
+    pub latitude: i128,
+    pub longitude: i128,
+    pub is_registered: bool,
+    pub total_waste_processed: u128,
+    pub total_tokens_earned: u128,
+    pub registered_at: u64,
+}
+
+/// Participant record as stored by schema version 2.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParticipantV2 {
+    pub address: Address,
+    pub role: ParticipantRole,
+    pub roles: u32,
+    pub name: Symbol,
+    pub latitude: i128,
+    pub longitude: i128,
+    pub is_registered: bool,
+    pub active: bool,
+    pub reputation: i32,
+    pub total_waste_processed: u128,
+    pub total_tokens_earned: u128,
+    pub registered_at: u64,
+}
+
+/// Instance storage key the original contract stored `address`'s record
+/// under.
+pub fn legacy_key(address: &Address) -> (Address,) {
+    (address.clone(),)
+}
+
+impl From<ParticipantV1> for Participant {
+    /// Fill the fields added since V1 with the values a fresh registration
+    /// would get: the stored role as the only role, active, neutral reputation.
+    fn from(old: ParticipantV1) -> Self {
+        Participant {
+            address: old.address,
+            role: old.role,
+            roles: old.role.bit(),
+            name: old.name,
+            latitude: old.latitude,
+            longitude: old.longitude,
+            location_verified: false,
+            is_registered: old.is_registered,
+            active: true,
+            reputation: 0,
+            total_waste_processed: old.total_waste_processed,
+            total_tokens_earned: old.total_tokens_earned,
+            registered_at: old.registered_at,
+        }
+    }
+}
+
+impl From<ParticipantV2> for Participant {
+    /// Locations recorded before oracle proofs existed are unverified.
+    fn from(old: ParticipantV2) -> Self {
+        Participant {
+            address: old.address,
+            role: old.role,
+            roles: old.roles,
+            name: old.name,
+            latitude: old.latitude,
+            longitude: old.longitude,
+            location_verified: false,
+            is_registered: old.is_registered,
+            active: old.active,
+            reputation: old.reputation,
+            total_waste_processed: old.total_waste_processed,
+            total_tokens_earned: old.total_tokens_earned,
+            registered_at: old.registered_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{self, DataKey};
+    use crate::{ScavengerContract, ScavengerContractClient};
+    use soroban_sdk::{symbol_short, testutils::Address as _, Env};
+
+    /// Write a record exactly as the original contract's `set_participant` did.
+    fn write_v1(env: &Env, address: &Address, role: ParticipantRole) {
+        let old = ParticipantV1 {
+            address: address.clone(),
+            role,
+            name: symbol_short!("old"),
+            latitude: 1_000_000,
+            longitude: -2_000_000,
+            is_registered: true,
+            total_waste_processed: 5_000,
+            total_tokens_earned: 70,
+            registered_at: 1_600_000_000,
+        };
+        env.storage().instance().set(&(address.clone(),), &old);
+    }
+
+    #[test]
+    fn test_v1_record_is_upgraded_on_read_with_defaults() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            write_v1(&env, &user, ParticipantRole::Collector);
+
+            let p = storage::get_participant(&env, &user).unwrap();
+            assert_eq!(p.role, ParticipantRole::Collector);
+            assert_eq!(p.roles, ParticipantRole::Collector.bit());
+            assert!(p.active);
+            assert_eq!(p.reputation, 0);
+            assert_eq!(p.name, symbol_short!("old"));
+            assert_eq!((p.latitude, p.longitude), (1_000_000, -2_000_000));
+            assert!(p.is_registered);
+            assert_eq!(p.total_waste_processed, 5_000);
+            assert_eq!(p.total_tokens_earned, 70);
+            assert_eq!(p.registered_at, 1_600_000_000);
+
+            // Reading alone does not rewrite the record.
+            assert_eq!(storage::participant_version(&env, &user), PARTICIPANT_V1);
+        });
+    }
+
+    #[test]
+    fn test_v2_record_keeps_fields_and_is_unverified() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let old = ParticipantV2 {
+                address: user.clone(),
+                role: ParticipantRole::Collector,
+                roles: ParticipantRole::Collector.bit() | ParticipantRole::Recycler.bit(),
+                name: symbol_short!("two"),
+                latitude: 3,
+                longitude: 4,
+                is_registered: true,
+                active: false,
+                reputation: -12,
+                total_waste_processed: 0,
+                total_tokens_earned: 0,
+                registered_at: 1_650_000_000,
+            };
+            let persistent = env.storage().persistent();
+            persistent.set(&DataKey::Participant(user.clone()), &old);
+            persistent.set(&DataKey::ParticipantVersion(user.clone()), &PARTICIPANT_V2);
+
+            let p = storage::get_participant(&env, &user).unwrap();
+            assert_eq!(p.roles, old.roles);
+            assert!(!p.active);
+            assert_eq!(p.reputation, -12);
+            assert!(!p.location_verified);
+        });
+    }
+
+    #[test]
+    fn test_migrate_participant_rewrites_current_layout() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        let client = ScavengerContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin);
+        env.as_contract(&contract_id, || write_v1(&env, &user, ParticipantRole::Recycler));
+
+        let migrated = client.migrate_participant(&admin, &user);
+        assert!(migrated.active);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                storage::participant_version(&env, &user),
+                PARTICIPANT_SCHEMA_VERSION
+            );
+            let raw: Participant = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Participant(user.clone()))
+                .unwrap();
+            assert_eq!(raw, migrated);
+            assert!(!env.storage().instance().has(&legacy_key(&user)));
+        });
+        // Migrated participants join the role index like new registrations.
+        let listed = client.list_participants(&ParticipantRole::Recycler, &0, &10);
+        assert_eq!(listed.get(0).unwrap().address, user);
+    }
+
+    #[test]
+    fn test_legacy_participant_survives_upgrade() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        let client = ScavengerContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        env.as_contract(&contract_id, || write_v1(&env, &user, ParticipantRole::Collector));
+
+        assert!(client.is_registered(&user));
+        assert_eq!(client.get_participant(&user).unwrap().name, symbol_short!("old"));
+        assert_eq!(
+            client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("new"), &0, &0),
+            Err(Ok(crate::Error::AlreadyRegistered))
+        );
+        client.bump_participant_ttl(&user);
+
+        // The first write moves the record out of instance storage.
+        let renamed = client.update_name(&user, &symbol_short!("moved"));
+        assert_eq!(renamed.total_tokens_earned, 70);
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().instance().has(&legacy_key(&user)));
+            assert_eq!(
+                storage::participant_version(&env, &user),
+                PARTICIPANT_SCHEMA_VERSION
+            );
+        });
+        assert_eq!(client.get_participant(&user).unwrap(), renamed);
+    }
+
+    #[test]
+    fn test_new_registrations_are_stored_at_current_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        let client = ScavengerContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("new"), &0, &0);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                storage::participant_version(&env, &user),
+                PARTICIPANT_SCHEMA_VERSION
+            );
+        });
+    }
+}
@@ -0,0 +1,91 @@
+//! Market prices for material kinds, maintained by a designated price admin.
+//!
+//! Prices are in the smallest unit of whatever currency the price admin
+//! quotes in, per gram. They are indicative only: nothing on-chain settles at
+//! these prices.
+
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage::DataKey;
+use crate::{MaterialBatch, MaterialKind};
+
+/// Address allowed to publish prices, if one is set.
+pub fn price_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PriceAdmin)
+}
+
+/// Designate the address allowed to publish prices.
+pub fn set_price_admin(env: &Env, price_admin: &Address) {
+    env.storage().instance().set(&DataKey::PriceAdmin, price_admin);
+}
+
+/// Current price per gram of `kind`, if one has been published.
+pub fn price(env: &Env, kind: MaterialKind) -> Option<i128> {
+    env.storage().instance().get(&DataKey::Price(kind))
+}
+
+/// Publish a new price per gram for `kind` and emit a `price` event.
+pub fn set_price(env: &Env, kind: MaterialKind, price_per_gram: i128) -> Result<(), Error> {
+    if price_per_gram <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::Price(kind), &price_per_gram);
+    events::emit_price_set(env, kind, price_per_gram);
+    Ok(())
+}
+
+/// Estimated market value of `batch` at the current price for its kind.
+pub fn estimate(env: &Env, batch: &MaterialBatch) -> Result<i128, Error> {
+    let price = price(env, batch.kind).ok_or(Error::NoPrice)?;
+    price
+        .checked_mul(batch.weight_grams as i128)
+        .ok_or(Error::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScavengerContract;
+    use crate::BatchStatus;
+    use soroban_sdk::testutils::Address as _;
+
+    fn batch(env: &Env, kind: MaterialKind, weight_grams: u64) -> MaterialBatch {
+        MaterialBatch {
+            id: 1,
+            owner: Address::generate(env),
+            kind,
+            weight_grams,
+            created_at: 0,
+            status: BatchStatus::Collected,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_estimate_requires_price() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let b = batch(&env, MaterialKind::Glass, 1_000);
+            assert_eq!(estimate(&env, &b), Err(Error::NoPrice));
+
+            set_price(&env, MaterialKind::Glass, 3).unwrap();
+            assert_eq!(estimate(&env, &b), Ok(3_000));
+        });
+    }
+
+    #[test]
+    fn test_estimate_overflow() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            set_price(&env, MaterialKind::Metal, i128::MAX).unwrap();
+            let b = batch(&env, MaterialKind::Metal, 2);
+            assert_eq!(estimate(&env, &b), Err(Error::Overflow));
+        });
+    }
+}
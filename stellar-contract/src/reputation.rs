@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+
+/// Largest adjustment a single rating can make, in either direction.
+pub const MAX_DELTA: i32 = 5;
+/// Bounds on a participant's cumulative reputation.
+pub const MIN_REPUTATION: i32 = -1000;
+pub const MAX_REPUTATION: i32 = 1000;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+/// ~one day of ledgers at 5 s close time; long enough for the rate-limit
+/// key to outlive the day it covers.
+const LEDGERS_PER_DAY: u32 = 17_280;
+
+/// Apply `delta` (clamped to ±[`MAX_DELTA`]) to `current`, keeping the result
+/// inside [`MIN_REPUTATION`, `MAX_REPUTATION`].
+pub fn apply_delta(current: i32, delta: i32) -> i32 {
+    let delta = delta.clamp(-MAX_DELTA, MAX_DELTA);
+    current
+        .saturating_add(delta)
+        .clamp(MIN_REPUTATION, MAX_REPUTATION)
+}
+
+/// Record that `rater` rated `target` today, failing if they already have.
+///
+/// Uses temporary storage keyed by the ledger day, so the marker expires on
+/// its own instead of accumulating.
+pub fn mark_rated_today(env: &Env, rater: &Address, target: &Address) -> Result<(), Error> {
+    let day = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let key = DataKey::RatedOn(rater.clone(), target.clone(), day);
+    let temp = env.storage().temporary();
+    if temp.has(&key) {
+        return Err(Error::AlreadyRated);
+    }
+    temp.set(&key, &true);
+    temp.extend_ttl(&key, LEDGERS_PER_DAY, LEDGERS_PER_DAY);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_delta_clamps_per_call() {
+        assert_eq!(apply_delta(0, 3), 3);
+        assert_eq!(apply_delta(0, 50), 5);
+        assert_eq!(apply_delta(0, -50), -5);
+        assert_eq!(apply_delta(0, i32::MIN), -5);
+    }
+
+    #[test]
+    fn test_apply_delta_clamps_cumulative() {
+        assert_eq!(apply_delta(998, 5), 1000);
+        assert_eq!(apply_delta(1000, 1), 1000);
+        assert_eq!(apply_delta(-998, -5), -1000);
+        assert_eq!(apply_delta(-1000, -1), -1000);
+    }
+}
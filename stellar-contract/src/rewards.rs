@@ -0,0 +1,246 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::{MaterialBatch, ParticipantRole, RewardSchedule};
+
+/// Grams of material per reward point unless the admin has tuned it.
+pub const DEFAULT_POINTS_DIVISOR: u64 = 100;
+
+/// Points per reward token unit unless the admin has tuned it.
+pub const DEFAULT_POINTS_PER_TOKEN: u64 = 100;
+
+/// Current reward-point balance of `address`.
+pub fn get_points(env: &Env, address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Points(address.clone()))
+        .unwrap_or(0)
+}
+
+/// Credit `amount` points to `to` and emit a `reward` event.
+///
+/// Zero-point awards are a no-op so small batches don't emit empty events.
+pub fn award_points(env: &Env, to: &Address, amount: u64) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let balance = get_points(env, to)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Points(to.clone()), &balance);
+    events::emit_points_awarded(env, to, amount);
+    Ok(())
+}
+
+/// Debit `amount` points from `from`.
+pub fn spend_points(env: &Env, from: &Address, amount: u64) -> Result<(), Error> {
+    let balance = get_points(env, from)
+        .checked_sub(amount)
+        .ok_or(Error::InsufficientPoints)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Points(from.clone()), &balance);
+    Ok(())
+}
+
+/// Points needed to redeem one unit of the reward token.
+pub fn points_per_token(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PointsPerToken)
+        .unwrap_or(DEFAULT_POINTS_PER_TOKEN)
+}
+
+/// Store a new redemption rate. Must be non-zero.
+pub fn set_points_per_token(env: &Env, rate: u64) -> Result<(), Error> {
+    if rate == 0 {
+        return Err(Error::InvalidAmount);
+    }
+    env.storage().instance().set(&DataKey::PointsPerToken, &rate);
+    Ok(())
+}
+
+/// Grams of material needed to earn one point.
+pub fn points_divisor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PointsDivisor)
+        .unwrap_or(DEFAULT_POINTS_DIVISOR)
+}
+
+/// Store a new points divisor. Must be non-zero.
+pub fn set_points_divisor(env: &Env, divisor: u64) -> Result<(), Error> {
+    if divisor == 0 {
+        return Err(Error::InvalidAmount);
+    }
+    env.storage().instance().set(&DataKey::PointsDivisor, &divisor);
+    Ok(())
+}
+
+/// The reward halving schedule, if the admin has set one.
+pub fn reward_schedule(env: &Env) -> Option<RewardSchedule> {
+    env.storage().instance().get(&DataKey::RewardSchedule)
+}
+
+/// Start a halving schedule at the current ledger. Rate and interval must be
+/// non-zero.
+pub fn set_reward_schedule(env: &Env, base_rate: u64, halving_interval_ledgers: u32) -> Result<(), Error> {
+    if base_rate == 0 || halving_interval_ledgers == 0 {
+        return Err(Error::InvalidAmount);
+    }
+    let schedule = RewardSchedule {
+        base_rate,
+        genesis_ledger: env.ledger().sequence(),
+        halving_interval_ledgers,
+    };
+    env.storage().instance().set(&DataKey::RewardSchedule, &schedule);
+    Ok(())
+}
+
+/// Points per [`points_divisor`] grams at the current ledger: 1 without a
+/// schedule.
+pub fn current_rate(env: &Env) -> u64 {
+    reward_schedule(env).map_or(1, |s| s.rate_at(env.ledger().sequence()))
+}
+
+/// Points earned for `weight_grams` of material at the current rate.
+pub fn points_for_weight(env: &Env, weight_grams: u64) -> u64 {
+    let points = weight_grams as u128 * current_rate(env) as u128 / points_divisor(env) as u128;
+    points.min(u64::MAX as u128) as u64
+}
+
+/// Split `total` points across `recipients` in proportion to `shares`.
+///
+/// Each recipient gets `total * share / sum(shares)`, rounded down, and the
+/// first recipient also gets whatever rounding leaves over, so exactly
+/// `total` points are awarded. Emits one `reward` event per recipient whose
+/// portion is non-zero.
+///
+/// # Errors
+/// - [`Error::InvalidAmount`] if the vectors differ in length or the shares
+///   sum to zero.
+pub fn award_split(
+    env: &Env,
+    recipients: &Vec<Address>,
+    shares: &Vec<u32>,
+    total: u64,
+) -> Result<(), Error> {
+    if recipients.len() != shares.len() {
+        return Err(Error::InvalidAmount);
+    }
+    let share_sum: u64 = shares.iter().map(u64::from).sum();
+    if share_sum == 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let mut portions = Vec::new(env);
+    let mut allocated = 0u64;
+    for share in shares.iter() {
+        let portion = (total as u128 * share as u128 / share_sum as u128) as u64;
+        allocated += portion;
+        portions.push_back(portion);
+    }
+    let remainder = total - allocated;
+
+    for (i, (recipient, portion)) in recipients.iter().zip(portions.iter()).enumerate() {
+        let portion = if i == 0 { portion + remainder } else { portion };
+        award_points(env, &recipient, portion)?;
+    }
+    Ok(())
+}
+
+/// Reward the collectors who handled a batch that has just been recycled.
+///
+/// Every custody holder with the `Collector` role shares the points the
+/// batch's weight is worth, one share per time they took custody. Batches
+/// no collector touched award nothing.
+pub fn award_collectors(env: &Env, batch: &MaterialBatch) -> Result<(), Error> {
+    let mut recipients: Vec<Address> = Vec::new(env);
+    let mut shares: Vec<u32> = Vec::new(env);
+    for entry in storage::get_custody(env, batch.id).iter() {
+        let is_collector = storage::get_participant(env, &entry.holder)
+            .is_some_and(|p| p.has_role(ParticipantRole::Collector));
+        if !is_collector {
+            continue;
+        }
+        match recipients.first_index_of(&entry.holder) {
+            Some(i) => shares.set(i, shares.get_unchecked(i) + 1),
+            None => {
+                recipients.push_back(entry.holder);
+                shares.push_back(1);
+            }
+        }
+    }
+    if recipients.is_empty() {
+        return Ok(());
+    }
+    award_split(env, &recipients, &shares, points_for_weight(env, batch.weight_grams))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScavengerContract;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::vec;
+
+    fn recipients(env: &Env, n: u32) -> Vec<Address> {
+        let mut out = Vec::new(env);
+        for _ in 0..n {
+            out.push_back(Address::generate(env));
+        }
+        out
+    }
+
+    fn balances(env: &Env, recipients: &Vec<Address>) -> Vec<u64> {
+        let mut out = Vec::new(env);
+        for r in recipients.iter() {
+            out.push_back(get_points(env, &r));
+        }
+        out
+    }
+
+    #[test]
+    fn test_award_split_uneven_remainder_to_first() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let to = recipients(&env, 3);
+            award_split(&env, &to, &vec![&env, 1, 1, 1], 100).unwrap();
+            assert_eq!(balances(&env, &to), vec![&env, 34, 33, 33]);
+        });
+    }
+
+    #[test]
+    fn test_award_split_weighted_shares_conserve_total() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let to = recipients(&env, 3);
+            award_split(&env, &to, &vec![&env, 2, 3, 4], 1_001).unwrap();
+            // Rounded down, the portions are 222 + 333 + 444 = 999
+            assert_eq!(balances(&env, &to), vec![&env, 224, 333, 444]);
+
+            let more = recipients(&env, 4);
+            award_split(&env, &more, &vec![&env, 7, 0, 5, 1], 97).unwrap();
+            let sum: u64 = balances(&env, &more).iter().sum();
+            assert_eq!(sum, 97);
+            assert_eq!(get_points(&env, &more.get(1).unwrap()), 0);
+        });
+    }
+
+    #[test]
+    fn test_award_split_rejects_bad_input() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ScavengerContract);
+        env.as_contract(&contract_id, || {
+            let to = recipients(&env, 2);
+            assert_eq!(award_split(&env, &to, &vec![&env, 1], 10), Err(Error::InvalidAmount));
+            assert_eq!(award_split(&env, &to, &vec![&env, 0, 0], 10), Err(Error::InvalidAmount));
+        });
+    }
+}
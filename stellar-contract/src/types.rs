@@ -1,5 +1,5 @@
 
-use soroban_sdk::{contracttype, Address, String, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Represents a transfer record in the recycling system
 /// This struct is fully compatible with Soroban storage and implements
@@ -296,6 +296,18 @@ pub enum ParticipantRole {
 }
 
 impl ParticipantRole {
+    /// Every role, in discriminant order
+    pub const ALL: [ParticipantRole; 3] = [
+        ParticipantRole::Recycler,
+        ParticipantRole::Collector,
+        ParticipantRole::Manufacturer,
+    ];
+
+    /// Bit representing this role in [`crate::Participant::roles`]
+    pub fn bit(&self) -> u32 {
+        1 << self.to_u32()
+    }
+
     /// Validates if the role is a valid ParticipantRole variant
     pub fn is_valid(role: u32) -> bool {
         matches!(role, 0..=2)
@@ -415,6 +427,221 @@ impl core::fmt::Display for WasteType {
     }
 }
 
+/// Kind of material carried by a [`MaterialBatch`]
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaterialKind {
+    /// Plastic of any resin type
+    Plastic = 0,
+    /// Glass bottles, jars, cullet
+    Glass = 1,
+    /// Ferrous and non-ferrous metals
+    Metal = 2,
+    /// Paper and cardboard
+    Paper = 3,
+    /// Electronic waste
+    EWaste = 4,
+}
+
+impl MaterialKind {
+    /// Converts the MaterialKind to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+}
+
+/// Processing stage of a [`MaterialBatch`]
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum BatchStatus {
+    /// Gathered by the recycler; the initial stage
+    Collected = 0,
+    /// On its way to the next participant
+    InTransit = 1,
+    /// Sorted, cleaned or otherwise processed
+    Processed = 2,
+    /// Turned back into raw material; the batch can no longer move
+    Recycled = 3,
+}
+
+impl BatchStatus {
+    /// Converts the BatchStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Checks if moving to `next` is allowed. Stages only move forward,
+    /// though intermediate ones may be skipped.
+    pub fn can_transition_to(&self, next: BatchStatus) -> bool {
+        next > *self
+    }
+
+    /// Checks if the status is final (cannot be changed)
+    pub fn is_final(&self) -> bool {
+        matches!(self, BatchStatus::Recycled)
+    }
+}
+
+/// A batch of recovered material moving through the supply chain.
+///
+/// Batches are created by recyclers and keyed by a sequential `u64` id.
+/// Unlike [`Material`], which records a single verified submission, a batch
+/// has a current `owner` that changes as it is handed on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaterialBatch {
+    /// Sequential batch id, starting at 1
+    pub id: u64,
+    /// Current holder of the batch
+    pub owner: Address,
+    /// Kind of material in the batch
+    pub kind: MaterialKind,
+    /// Batch weight in grams
+    pub weight_grams: u64,
+    /// Ledger timestamp at creation
+    pub created_at: u64,
+    /// Current processing stage
+    pub status: BatchStatus,
+    /// Ledger timestamp from which a perishable batch counts as expired
+    pub expires_at: Option<u64>,
+}
+
+/// Grams in a kilogram
+pub const GRAMS_PER_KG: u64 = 1_000;
+
+/// Splits `grams` into whole kilograms and the grams left over, so a UI can
+/// show e.g. `1.001 kg` without floating point
+pub fn to_kg(grams: u64) -> (u64, u64) {
+    (grams / GRAMS_PER_KG, grams % GRAMS_PER_KG)
+}
+
+impl MaterialBatch {
+    /// Batch weight as whole kilograms and remainder grams; see [`to_kg`]
+    pub fn weight_kg(&self) -> (u64, u64) {
+        to_kg(self.weight_grams)
+    }
+
+    /// Checks if the batch has expired as of ledger timestamp `now`
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|at| now >= at)
+    }
+}
+
+/// Location oracle's attestation of a participant's coordinates
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocationProof {
+    /// Ledger timestamp at which the oracle signed
+    pub timestamp: u64,
+    /// Oracle's ed25519 signature over `(address, latitude, longitude, timestamp)`
+    pub signature: BytesN<64>,
+}
+
+/// One link in a [`MaterialBatch`]'s chain of custody
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyEntry {
+    /// Participant who took custody
+    pub holder: Address,
+    /// Ledger timestamp at which custody began
+    pub received_at: u64,
+}
+
+/// Share of a [`MaterialBatch`]'s weight that one creator contributed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchOrigin {
+    /// Participant who created the material
+    pub creator: Address,
+    /// Grams of the batch that came from `creator`
+    pub weight_grams: u64,
+}
+
+/// Open dispute over a [`MaterialBatch`]'s recorded weight or kind
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    /// Custody-chain participant who raised the dispute
+    pub flagger: Address,
+    /// Free-text explanation
+    pub reason: String,
+    /// Ledger timestamp at which the dispute was raised
+    pub flagged_at: u64,
+}
+
+/// Tokens a buyer has locked in the contract against delivery of a batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    /// Participant who offered payment and receives the batch
+    pub buyer: Address,
+    /// Token contract the payment is made in
+    pub token: Address,
+    /// Amount locked, in the token's smallest unit
+    pub amount: i128,
+    /// Ledger timestamp at which the offer was made
+    pub created_at: u64,
+}
+
+/// How a [`ContactInfo`] handle should be used
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContactKind {
+    /// Phone number
+    Phone = 0,
+    /// Email address
+    Email = 1,
+    /// Web page or messaging link
+    Url = 2,
+}
+
+/// How to reach a participant, stored apart from the [`crate::Participant`]
+/// record so ordinary participant reads don't load it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContactInfo {
+    /// Phone number, email address or URL, up to 128 bytes
+    pub handle: String,
+    /// What `handle` is
+    pub kind: ContactKind,
+}
+
+/// Everything recorded about a [`MaterialBatch`], gathered for auditors
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReport {
+    /// The batch record itself
+    pub batch: MaterialBatch,
+    /// Ownership history, oldest first
+    pub custody: Vec<CustodyEntry>,
+    /// Grams of CO2 saved, or 0 until the batch is recycled
+    pub carbon_saved: u64,
+    /// Value at the current price for its kind, or 0 if none is published
+    pub estimated_value: i128,
+    /// Whether the batch is frozen by an open dispute
+    pub disputed: bool,
+}
+
+/// Halving schedule for the points earned per unit of material
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardSchedule {
+    /// Reward multiplier before the first halving
+    pub base_rate: u64,
+    /// Ledger sequence at which the schedule started
+    pub genesis_ledger: u32,
+    /// Ledgers between halvings
+    pub halving_interval_ledgers: u32,
+}
+
+impl RewardSchedule {
+    /// Multiplier in effect at ledger sequence `ledger`, never below 1
+    pub fn rate_at(&self, ledger: u32) -> u64 {
+        let epochs = ledger.saturating_sub(self.genesis_ledger) / self.halving_interval_ledgers;
+        self.base_rate.checked_shr(epochs).unwrap_or(0).max(1)
+    }
+}
+
 /// Represents a recyclable material submission in the system
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1227,10 +1454,50 @@ mod waste_type_tests {
     }
 }
 
+#[cfg(test)]
+mod batch_status_tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_transitions_allowed() {
+        assert!(BatchStatus::Collected.can_transition_to(BatchStatus::InTransit));
+        assert!(BatchStatus::InTransit.can_transition_to(BatchStatus::Processed));
+        assert!(BatchStatus::Processed.can_transition_to(BatchStatus::Recycled));
+        assert!(BatchStatus::Collected.can_transition_to(BatchStatus::Recycled));
+    }
+
+    #[test]
+    fn test_backward_and_same_transitions_rejected() {
+        assert!(!BatchStatus::Recycled.can_transition_to(BatchStatus::Collected));
+        assert!(!BatchStatus::Processed.can_transition_to(BatchStatus::InTransit));
+        assert!(!BatchStatus::InTransit.can_transition_to(BatchStatus::InTransit));
+    }
+
+    #[test]
+    fn test_only_recycled_is_final() {
+        assert!(BatchStatus::Recycled.is_final());
+        assert!(!BatchStatus::Collected.is_final());
+        assert!(!BatchStatus::InTransit.is_final());
+        assert!(!BatchStatus::Processed.is_final());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_to_kg_boundaries() {
+        assert_eq!(to_kg(0), (0, 0));
+        assert_eq!(to_kg(1), (0, 1));
+        assert_eq!(to_kg(999), (0, 999));
+        assert_eq!(to_kg(1_000), (1, 0));
+        assert_eq!(to_kg(1_001), (1, 1));
+        assert_eq!(to_kg(1_999), (1, 999));
+        assert_eq!(to_kg(2_000), (2, 0));
+        assert_eq!(to_kg(u64::MAX), (u64::MAX / 1_000, 615));
+    }
+
     #[test]
     fn test_role_values() {
         assert_eq!(ParticipantRole::Recycler as u32, 0);
@@ -1,4 +1,13 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::errors::Error;
+
+/// Coordinate bounds in microdegrees (degrees scaled by 1e6).
+const MAX_LAT: i128 = 90_000_000;
+const MAX_LON: i128 = 180_000_000;
+
+/// Longest contact handle accepted, in bytes.
+const MAX_CONTACT_HANDLE_LEN: u32 = 128;
 
 pub fn validate_positive_amount(amount: i128, field_name: &str) {
     if amount <= 0 {
@@ -12,17 +21,40 @@ pub fn validate_percentage(percentage: u32, field_name: &str) {
     }
 }
 
-pub fn validate_coordinates(latitude: i128, longitude: i128) {
-    const MAX_LAT: i128 = 90_000_000;
-    const MAX_LON: i128 = 180_000_000;
+/// Reject latitudes outside ±90° and longitudes outside ±180°.
+pub fn check_coordinates(latitude: i128, longitude: i128) -> Result<(), Error> {
+    if !(-MAX_LAT..=MAX_LAT).contains(&latitude) || !(-MAX_LON..=MAX_LON).contains(&longitude) {
+        return Err(Error::InvalidCoordinates);
+    }
+    Ok(())
+}
 
-    if latitude < -MAX_LAT || latitude > MAX_LAT {
-        panic!("Latitude must be between -90 and +90 degrees");
+/// Reject empty contact handles and ones over 128 bytes.
+pub fn check_contact_handle(handle: &String) -> Result<(), Error> {
+    if handle.is_empty() || handle.len() > MAX_CONTACT_HANDLE_LEN {
+        return Err(Error::InvalidHandle);
     }
+    Ok(())
+}
+
+/// Reject zero weights. Weights are canonical grams everywhere; see
+/// [`crate::to_kg`] for display.
+pub fn check_weight(weight_grams: u64) -> Result<(), Error> {
+    if weight_grams == 0 {
+        return Err(Error::InvalidWeight);
+    }
+    Ok(())
+}
 
-    if longitude < -MAX_LON || longitude > MAX_LON {
-        panic!("Longitude must be between -180 and +180 degrees");
+/// Reject empty participant names.
+///
+/// Names are `Symbol`s, which the host already limits to 32 characters from
+/// `[a-zA-Z0-9_]`, so only the lower bound needs checking here.
+pub fn check_name(env: &Env, name: &Symbol) -> Result<(), Error> {
+    if *name == Symbol::new(env, "") {
+        return Err(Error::InvalidName);
     }
+    Ok(())
 }
 
 pub fn validate_address_not_contract(env: &Env, address: &Address) {
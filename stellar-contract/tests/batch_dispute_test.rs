@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+struct Fixture<'a> {
+    client: ScavengerContractClient<'a>,
+    admin: Address,
+    recycler: Address,
+    collector: Address,
+    batch_id: u64,
+}
+
+/// A batch created by `recycler` and handed to `collector`.
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let batch_id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &2_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&batch_id, &collector);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+
+    Fixture { client, admin, recycler, collector, batch_id }
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "weight short by 200g")
+}
+
+#[test]
+fn test_past_holder_can_flag_batch() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    f.client.flag_batch(&f.recycler, &f.batch_id, &reason(&env));
+
+    let dispute = f.client.get_dispute(&f.batch_id).unwrap();
+    assert_eq!(dispute.flagger, f.recycler);
+    assert_eq!(dispute.reason, reason(&env));
+    assert_eq!(dispute.flagged_at, 300);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("dispute"), f.batch_id).into_val(&env);
+    assert_eq!(topics, expected);
+    let (flagger, text): (Address, String) = data.into_val(&env);
+    assert_eq!((flagger, text), (f.recycler.clone(), reason(&env)));
+}
+
+#[test]
+fn test_outsider_cannot_flag_batch() {
+    let env = Env::default();
+    let f = setup(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        f.client.try_flag_batch(&outsider, &f.batch_id, &reason(&env)),
+        Err(Ok(Error::NotCustodian))
+    );
+    assert_eq!(
+        f.client.try_flag_batch(&f.recycler, &99, &reason(&env)),
+        Err(Ok(Error::BatchNotFound))
+    );
+}
+
+#[test]
+fn test_flagging_twice_fails() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.flag_batch(&f.collector, &f.batch_id, &reason(&env));
+
+    assert_eq!(
+        f.client.try_flag_batch(&f.recycler, &f.batch_id, &reason(&env)),
+        Err(Ok(Error::AlreadyDisputed))
+    );
+}
+
+#[test]
+fn test_disputed_batch_is_frozen() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.flag_batch(&f.collector, &f.batch_id, &reason(&env));
+
+    assert_eq!(
+        f.client.try_transfer_material(&f.batch_id, &f.recycler),
+        Err(Ok(Error::BatchDisputed))
+    );
+    assert_eq!(
+        f.client.try_set_batch_status(&f.batch_id, &BatchStatus::Processed),
+        Err(Ok(Error::BatchDisputed))
+    );
+    assert_eq!(
+        f.client.try_split_batch(&f.batch_id, &vec![&env, 1_000, 1_000]),
+        Err(Ok(Error::BatchDisputed))
+    );
+}
+
+#[test]
+fn test_resolve_dispute_unfreezes_batch() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.flag_batch(&f.collector, &f.batch_id, &reason(&env));
+
+    f.client.resolve_dispute(&f.admin, &f.batch_id);
+
+    assert_eq!(f.client.get_dispute(&f.batch_id), None);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("resolved"), f.batch_id).into_val(&env);
+    assert_eq!(topics, expected);
+
+    f.client.set_batch_status(&f.batch_id, &BatchStatus::Processed);
+}
+
+#[test]
+fn test_resolve_without_dispute_fails() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    assert_eq!(
+        f.client.try_resolve_dispute(&f.admin, &f.batch_id),
+        Err(Ok(Error::NotDisputed))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_resolve_dispute_requires_admin() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.flag_batch(&f.collector, &f.batch_id, &reason(&env));
+
+    f.client.resolve_dispute(&f.collector, &f.batch_id);
+}
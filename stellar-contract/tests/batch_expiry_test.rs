@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+struct Fixture<'a> {
+    client: ScavengerContractClient<'a>,
+    admin: Address,
+    recycler: Address,
+    collector: Address,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    Fixture { client, admin, recycler, collector }
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_perishable_batch_records_expiry() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    let id = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &2_000);
+
+    assert_eq!(f.client.get_material_batch(&id).unwrap().expires_at, Some(2_000));
+    assert!(!f.client.is_expired(&id));
+    set_time(&env, 2_000);
+    assert!(f.client.is_expired(&id));
+}
+
+#[test]
+fn test_regular_batches_never_expire() {
+    let env = Env::default();
+    let f = setup(&env);
+    let id = f.client.create_material_batch(&f.recycler, &MaterialKind::Paper, &500);
+
+    set_time(&env, u64::MAX);
+    assert_eq!(f.client.get_material_batch(&id).unwrap().expires_at, None);
+    assert!(!f.client.is_expired(&id));
+    assert!(!f.client.is_expired(&42));
+}
+
+#[test]
+fn test_expiry_must_be_in_future() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    assert_eq!(
+        f.client.try_create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &1_000),
+        Err(Ok(Error::BatchExpired))
+    );
+}
+
+#[test]
+fn test_expired_batch_cannot_move_or_advance() {
+    let env = Env::default();
+    let f = setup(&env);
+    let id = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &2_000);
+
+    set_time(&env, 2_000);
+    assert_eq!(
+        f.client.try_transfer_material(&id, &f.collector),
+        Err(Ok(Error::BatchExpired))
+    );
+    assert_eq!(
+        f.client.try_set_batch_status(&id, &BatchStatus::Processed),
+        Err(Ok(Error::BatchExpired))
+    );
+}
+
+#[test]
+fn test_split_and_merge_carry_expiry() {
+    let env = Env::default();
+    let f = setup(&env);
+    let a = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &5_000);
+    let b = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &3_000);
+    let c = f.client.create_material_batch(&f.recycler, &MaterialKind::Paper, &500);
+
+    set_time(&env, 1_100);
+    let children = f.client.split_batch(&a, &vec![&env, 200, 300]);
+    let child = f.client.get_material_batch(&children.get(0).unwrap()).unwrap();
+    assert_eq!(child.expires_at, Some(5_000));
+
+    let merged = f.client.merge_batches(&f.recycler, &vec![&env, b, c]);
+    assert_eq!(f.client.get_material_batch(&merged).unwrap().expires_at, Some(3_000));
+}
+
+#[test]
+fn test_purge_expired_deletes_only_expired() {
+    let env = Env::default();
+    let f = setup(&env);
+    let stale = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &2_000);
+    let fresh = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &9_000);
+
+    set_time(&env, 2_500);
+    let purged = f.client.purge_expired(&f.admin, &vec![&env, stale, fresh, 99]);
+
+    assert_eq!(purged, 1);
+    assert!(f.client.get_material_batch(&stale).is_none());
+    assert_eq!(f.client.get_custody_chain(&stale).len(), 0);
+    assert!(f.client.get_material_batch(&fresh).is_some());
+}
+
+#[test]
+fn test_purge_emits_expired_event() {
+    let env = Env::default();
+    let f = setup(&env);
+    let id = f.client.create_perishable_batch(&f.recycler, &MaterialKind::Paper, &500, &2_000);
+
+    set_time(&env, 2_000);
+    f.client.purge_expired(&f.admin, &vec![&env, id]);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> = (symbol_short!("expired"), id).into_val(&env);
+    assert_eq!(topics, expected);
+    let owner: Address = data.into_val(&env);
+    assert_eq!(owner, f.recycler);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_purge_expired_requires_admin() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    f.client.purge_expired(&f.collector, &vec![&env, 1]);
+}
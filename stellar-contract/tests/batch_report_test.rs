@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup_with_admin};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use stellar_scavngr_contract::{BatchStatus, Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_batch_report_gathers_every_subsystem() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let price_admin = Address::generate(&env);
+    client.set_price_admin(&admin, &price_admin);
+    client.set_price(&price_admin, &MaterialKind::Plastic, &3);
+
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &2_000);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.transfer_material(&id, &collector);
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+
+    let report = client.batch_report(&id);
+    assert_eq!(report.batch, client.get_material_batch(&id).unwrap());
+    assert_eq!(report.custody, client.get_custody_chain(&id));
+    assert_eq!(report.custody.len(), 2);
+    assert_eq!(report.carbon_saved, client.carbon_saved(&id));
+    assert_eq!(report.carbon_saved, 3_000);
+    assert_eq!(report.estimated_value, 6_000);
+    assert!(!report.disputed);
+}
+
+#[test]
+fn test_batch_report_defaults_missing_data() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &500);
+
+    let report = client.batch_report(&id);
+    assert_eq!(report.carbon_saved, 0);
+    assert_eq!(report.estimated_value, 0);
+    assert!(!report.disputed);
+}
+
+#[test]
+fn test_batch_report_shows_dispute() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &500);
+
+    client.flag_batch(&recycler, &id, &String::from_str(&env, "weight looks off"));
+
+    assert!(client.batch_report(&id).disputed);
+}
+
+#[test]
+fn test_batch_report_unknown_batch() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    assert_eq!(client.try_batch_report(&7), Err(Ok(Error::BatchNotFound)));
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    (client, recycler, collector, id)
+}
+
+#[test]
+fn test_new_batch_is_collected() {
+    let env = Env::default();
+    let (client, _, _, id) = setup(&env);
+
+    assert_eq!(client.get_material_batch(&id).unwrap().status, BatchStatus::Collected);
+}
+
+#[test]
+fn test_set_batch_status_moves_forward_and_emits_event() {
+    let env = Env::default();
+    let (client, _, _, id) = setup(&env);
+
+    let batch = client.set_batch_status(&id, &BatchStatus::InTransit);
+    assert_eq!(batch.status, BatchStatus::InTransit);
+    assert_eq!(client.get_material_batch(&id).unwrap().status, BatchStatus::InTransit);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("status"), id).into_val(&env);
+    assert_eq!(topics, expected);
+    let status: BatchStatus = data.into_val(&env);
+    assert_eq!(status, BatchStatus::InTransit);
+}
+
+#[test]
+fn test_set_batch_status_rejects_backward_and_repeat() {
+    let env = Env::default();
+    let (client, _, _, id) = setup(&env);
+
+    client.set_batch_status(&id, &BatchStatus::Processed);
+    assert_eq!(
+        client.try_set_batch_status(&id, &BatchStatus::InTransit),
+        Err(Ok(Error::InvalidTransition))
+    );
+    assert_eq!(
+        client.try_set_batch_status(&id, &BatchStatus::Processed),
+        Err(Ok(Error::InvalidTransition))
+    );
+
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+    assert_eq!(
+        client.try_set_batch_status(&id, &BatchStatus::Collected),
+        Err(Ok(Error::InvalidTransition))
+    );
+}
+
+#[test]
+fn test_recycled_batch_cannot_be_transferred() {
+    let env = Env::default();
+    let (client, recycler, collector, id) = setup(&env);
+
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+    assert_eq!(
+        client.try_transfer_material(&id, &collector),
+        Err(Ok(Error::BatchRecycled))
+    );
+    assert_eq!(client.get_material_batch(&id).unwrap().owner, recycler);
+}
+
+#[test]
+fn test_status_survives_transfer() {
+    let env = Env::default();
+    let (client, _, collector, id) = setup(&env);
+
+    client.set_batch_status(&id, &BatchStatus::InTransit);
+    let batch = client.transfer_material(&id, &collector);
+    assert_eq!(batch.status, BatchStatus::InTransit);
+
+    // The new owner can advance it further.
+    assert_eq!(client.set_batch_status(&id, &BatchStatus::Processed).status, BatchStatus::Processed);
+}
+
+#[test]
+fn test_set_batch_status_unknown_batch() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_batch_status(&99, &BatchStatus::InTransit),
+        Err(Ok(Error::BatchNotFound))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_batch_status_requires_owner_auth() {
+    let env = Env::default();
+    let (client, _, _, id) = setup(&env);
+
+    env.set_auths(&[]);
+    client.set_batch_status(&id, &BatchStatus::InTransit);
+}
@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+use stellar_scavngr_contract::{BatchStatus, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_carbon_saved_is_zero_until_recycled() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &10_000);
+
+    assert_eq!(client.carbon_saved(&id), 0);
+    client.set_batch_status(&id, &BatchStatus::Processed);
+    assert_eq!(client.carbon_saved(&id), 0);
+
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+    assert_eq!(client.carbon_saved(&id), 3_000);
+}
+
+#[test]
+fn test_carbon_saved_unknown_batch_is_zero() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.carbon_saved(&7), 0);
+}
+
+#[test]
+fn test_metal_saves_more_than_glass() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let glass = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    let metal = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    client.set_batch_status(&glass, &BatchStatus::Recycled);
+    client.set_batch_status(&metal, &BatchStatus::Recycled);
+
+    assert!(client.carbon_saved(&metal) > client.carbon_saved(&glass));
+}
+
+#[test]
+fn test_total_accrues_to_creator_after_transfer() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Paper, &2_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&a, &manufacturer);
+
+    client.set_batch_status(&a, &BatchStatus::Recycled);
+    assert_eq!(client.participant_carbon_total(&recycler), 9_000);
+    assert_eq!(client.participant_carbon_total(&manufacturer), 0);
+
+    client.set_batch_status(&b, &BatchStatus::Recycled);
+    assert_eq!(client.participant_carbon_total(&recycler), 11_000);
+}
+
+#[test]
+fn test_split_children_credit_original_creator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let parent = client.create_material_batch(&recycler, &MaterialKind::Plastic, &2_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&parent, &collector);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    let children = client.split_batch(&parent, &vec![&env, 1_000, 1_000]);
+
+    client.set_batch_status(&children.get(0).unwrap(), &BatchStatus::Recycled);
+    assert_eq!(client.participant_carbon_total(&recycler), 1_500);
+    assert_eq!(client.participant_carbon_total(&collector), 0);
+}
+
+#[test]
+fn test_merged_batch_credits_each_creator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let first = register(&client, &env, ParticipantRole::Recycler);
+    let second = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    let a = client.create_material_batch(&first, &MaterialKind::Metal, &1_000);
+    let b = client.create_material_batch(&second, &MaterialKind::Metal, &3_000);
+    client.transfer_material(&a, &collector);
+    client.transfer_material(&b, &collector);
+    let merged = client.merge_batches(&collector, &vec![&env, a, b]);
+
+    client.set_batch_status(&merged, &BatchStatus::Recycled);
+    assert_eq!(client.participant_carbon_total(&first), 9_000);
+    assert_eq!(client.participant_carbon_total(&second), 27_000);
+    assert_eq!(client.participant_carbon_total(&collector), 0);
+}
+
+#[test]
+fn test_split_of_merged_batch_keeps_creator_shares() {
+    let env = Env::default();
+    let client = setup(&env);
+    let first = register(&client, &env, ParticipantRole::Recycler);
+    let second = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    let a = client.create_material_batch(&first, &MaterialKind::Metal, &1_000);
+    let b = client.create_material_batch(&second, &MaterialKind::Metal, &1_000);
+    client.transfer_material(&a, &collector);
+    client.transfer_material(&b, &collector);
+    let merged = client.merge_batches(&collector, &vec![&env, a, b]);
+    let parts = client.split_batch(&merged, &vec![&env, 500u64, 1_500u64]);
+
+    let small = parts.get(0).unwrap();
+    let origins = client.get_batch_origins(&small);
+    assert_eq!(origins.get(0).unwrap().weight_grams, 250);
+    assert_eq!(origins.get(1).unwrap().weight_grams, 250);
+
+    client.set_batch_status(&small, &BatchStatus::Recycled);
+    assert_eq!(client.participant_carbon_total(&first), 2_250);
+    assert_eq!(client.participant_carbon_total(&second), 2_250);
+}
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{
+    BatchStatus, MaterialKind, ParticipantRole, ScavengerContractClient,
+};
+
+fn hand_over(client: &ScavengerContractClient, env: &Env, batch_id: u64, to: &Address) {
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.transfer_material(&batch_id, to);
+}
+
+#[test]
+fn test_recycling_splits_reward_between_collectors() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let first = register(&client, &env, ParticipantRole::Collector);
+    let second = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+
+    // 1 kg at the default 100 g per point is worth 10 points
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    hand_over(&client, &env, id, &first);
+    hand_over(&client, &env, id, &second);
+    hand_over(&client, &env, id, &first);
+    hand_over(&client, &env, id, &manufacturer);
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+
+    // Shares 2:1 give 6 and 3; the leftover point goes to the first collector
+    assert_eq!(client.get_points(&first), 7);
+    assert_eq!(client.get_points(&second), 3);
+    assert_eq!(client.get_points(&manufacturer), 0);
+    assert_eq!(client.get_points(&recycler), 10);
+}
+
+#[test]
+fn test_recycling_without_collectors_awards_nothing() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    hand_over(&client, &env, id, &manufacturer);
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+
+    assert_eq!(client.get_points(&manufacturer), 0);
+    assert_eq!(client.get_points(&recycler), 10);
+}
+
+#[test]
+fn test_only_recycled_status_awards_collectors() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &500);
+    hand_over(&client, &env, id, &collector);
+    client.set_batch_status(&id, &BatchStatus::Processed);
+    assert_eq!(client.get_points(&collector), 0);
+
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+    assert_eq!(client.get_points(&collector), 5);
+}
@@ -0,0 +1,38 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Each test binary compiles its own copy of this module and uses only part
+//! of it.
+#![allow(dead_code)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+/// Register the contract with every auth mocked.
+pub fn setup(env: &Env) -> ScavengerContractClient<'_> {
+    env.mock_all_auths();
+    ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract))
+}
+
+/// Like [`setup`], with an admin initialised.
+pub fn setup_with_admin(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = setup(env);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+/// Register a fresh address with `role` at (0, 0).
+pub fn register(client: &ScavengerContractClient, env: &Env, role: ParticipantRole) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &symbol_short!("p"), &0, &0);
+    address
+}
+
+/// Move the ledger clock forward a few seconds.
+pub fn advance(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += 5);
+}
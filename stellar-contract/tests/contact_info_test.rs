@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, String,
+};
+use stellar_scavngr_contract::{
+    ContactInfo, ContactKind, Error, ParticipantRole, ScavengerContractClient,
+};
+
+fn register(client: &ScavengerContractClient, env: &Env) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    address
+}
+
+#[test]
+fn test_set_and_get_contact() {
+    let env = Env::default();
+    let client = setup(&env);
+    let collector = register(&client, &env);
+    assert_eq!(client.get_contact(&collector), None);
+
+    let handle = String::from_str(&env, "collector@example.com");
+    client.set_contact(&collector, &handle, &ContactKind::Email);
+    assert_eq!(
+        client.get_contact(&collector),
+        Some(ContactInfo { handle, kind: ContactKind::Email })
+    );
+
+    let phone = String::from_str(&env, "+15551234567");
+    client.set_contact(&collector, &phone, &ContactKind::Phone);
+    assert_eq!(client.get_contact(&collector).unwrap().kind, ContactKind::Phone);
+}
+
+#[test]
+fn test_set_contact_emits_no_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let collector = register(&client, &env);
+    let before = env.events().all().len();
+
+    client.set_contact(&collector, &String::from_str(&env, "https://example.com"), &ContactKind::Url);
+
+    assert_eq!(env.events().all().len(), before);
+}
+
+#[test]
+fn test_contact_handle_length_limits() {
+    let env = Env::default();
+    let client = setup(&env);
+    let collector = register(&client, &env);
+
+    let longest = String::from_bytes(&env, &[b'a'; 128]);
+    client.set_contact(&collector, &longest, &ContactKind::Url);
+
+    assert_eq!(
+        client.try_set_contact(&collector, &String::from_bytes(&env, &[b'a'; 129]), &ContactKind::Url),
+        Err(Ok(Error::InvalidHandle))
+    );
+    assert_eq!(
+        client.try_set_contact(&collector, &String::from_str(&env, ""), &ContactKind::Url),
+        Err(Ok(Error::InvalidHandle))
+    );
+    assert_eq!(client.get_contact(&collector).unwrap().handle, longest);
+}
+
+#[test]
+fn test_set_contact_requires_registration() {
+    let env = Env::default();
+    let client = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_contact(&stranger, &String::from_str(&env, "x"), &ContactKind::Phone),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
+
+#[test]
+fn test_contact_cleared_by_owner_and_on_removal() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let collector = register(&client, &env);
+    let handle = String::from_str(&env, "+15551234567");
+
+    client.set_contact(&collector, &handle, &ContactKind::Phone);
+    client.clear_contact(&collector);
+    assert_eq!(client.get_contact(&collector), None);
+
+    client.set_contact(&collector, &handle, &ContactKind::Phone);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &collector);
+    assert_eq!(client.get_contact(&collector), None);
+}
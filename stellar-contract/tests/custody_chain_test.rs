@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{testutils::Ledger, Env};
+use stellar_scavngr_contract::{MaterialKind, ParticipantRole};
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_custody_chain_starts_with_creator() {
+    let env = Env::default();
+    let client = setup(&env);
+    set_time(&env, 100);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 1);
+    let first = chain.get(0).unwrap();
+    assert_eq!(first.holder, recycler);
+    assert_eq!(first.received_at, 100);
+}
+
+#[test]
+fn test_custody_chain_appends_on_each_transfer() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+
+    set_time(&env, 100);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    set_time(&env, 200);
+    client.transfer_material(&id, &collector);
+    set_time(&env, 300);
+    client.transfer_material(&id, &manufacturer);
+
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0).unwrap().holder, recycler);
+    assert_eq!(chain.get(1).unwrap().holder, collector);
+    assert_eq!(chain.get(2).unwrap().holder, manufacturer);
+    assert_eq!(chain.get(1).unwrap().received_at, 200);
+    assert_eq!(chain.get(2).unwrap().received_at, 300);
+}
+
+#[test]
+fn test_custody_chain_same_timestamp_transfers_keep_order() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+
+    set_time(&env, 100);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    client.transfer_material(&id, &collector);
+    client.transfer_material(&id, &manufacturer);
+
+    // Hand-overs in one ledger share its timestamp and keep their order.
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(1).unwrap().holder, collector);
+    assert_eq!(chain.get(2).unwrap().holder, manufacturer);
+    for entry in chain.iter() {
+        assert_eq!(entry.received_at, 100);
+    }
+}
+
+#[test]
+fn test_custody_chain_unknown_batch_is_empty() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.get_custody_chain(&9).len(), 0);
+}
+
+#[test]
+fn test_custody_chains_are_per_batch() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    set_time(&env, 100);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Paper, &1_000);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Paper, &1_000);
+    set_time(&env, 200);
+    client.transfer_material(&a, &collector);
+
+    assert_eq!(client.get_custody_chain(&a).len(), 2);
+    assert_eq!(client.get_custody_chain(&b).len(), 1);
+}
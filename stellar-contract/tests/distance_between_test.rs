@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, ParticipantRole};
+
+#[test]
+fn test_distance_between_participants() {
+    let env = Env::default();
+    let client = setup(&env);
+    let london = Address::generate(&env);
+    let paris = Address::generate(&env);
+    client.register_participant(&london, &ParticipantRole::Recycler, &symbol_short!("ldn"), &51_507_400, &-127_800);
+    client.register_participant(&paris, &ParticipantRole::Collector, &symbol_short!("par"), &48_856_600, &2_352_200);
+
+    let d = client.distance_between(&london, &paris);
+    // ~343.6 km
+    assert!((340_000..347_000).contains(&d), "unexpected distance {}", d);
+    assert_eq!(client.distance_between(&paris, &london), d);
+}
+
+#[test]
+fn test_distance_between_follows_location_updates() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    client.register_participant(&a, &ParticipantRole::Recycler, &symbol_short!("a"), &0, &0);
+    client.register_participant(&b, &ParticipantRole::Collector, &symbol_short!("b"), &0, &0);
+    assert_eq!(client.distance_between(&a, &b), 0);
+
+    client.update_participant_location(&b, &1_000_000, &0);
+    // One degree of latitude is ~111.2 km.
+    let d = client.distance_between(&a, &b);
+    assert!((110_000..112_500).contains(&d), "unexpected distance {}", d);
+}
+
+#[test]
+fn test_distance_between_unknown_participant() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.register_participant(&a, &ParticipantRole::Recycler, &symbol_short!("a"), &0, &0);
+
+    assert_eq!(
+        client.try_distance_between(&a, &stranger),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+    assert_eq!(
+        client.try_distance_between(&stranger, &a),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
@@ -111,3 +111,33 @@ fn test_transfer_to_self_is_rejected() {
     let material = client.submit_material(&WasteType::Plastic, &5_000, &recycler, &String::from_str(&env, ""));
     client.transfer_waste(&material.id, &recycler, &recycler, &String::from_str(&env, ""));
 }
+
+// ── Multi-role: an added collector role earns the collector share ───────────
+#[test]
+fn test_added_collector_role_earns_incentive_share() {
+    let env = Env::default();
+    let (client, _admin, token) = setup(&env);
+
+    let rewarder = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    let multi = Address::generate(&env);
+    let manufacturer = Address::generate(&env);
+
+    client.register_participant(&rewarder, &ParticipantRole::Manufacturer, &symbol_short!("Rwd"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("Rec"), &0, &0);
+    client.register_participant(&multi, &ParticipantRole::Manufacturer, &symbol_short!("Multi"), &0, &0);
+    client.add_role(&multi, &ParticipantRole::Collector);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("Mfr"), &0, &0);
+
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&rewarder, &1_000_000);
+
+    // 10 pts/kg on 5 kg → 50; collectors take 10%
+    let incentive = client.create_incentive(&rewarder, &WasteType::Plastic, &10, &500);
+    let material = client.submit_material(&WasteType::Plastic, &5_000, &recycler, &String::from_str(&env, ""));
+    client.transfer_waste(&material.id, &recycler, &multi, &String::from_str(&env, ""));
+    client.transfer_waste(&material.id, &multi, &manufacturer, &String::from_str(&env, ""));
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.distribute_rewards(&material.id, &incentive.id, &rewarder), 50);
+    assert_eq!(soroban_sdk::token::Client::new(&env, &token).balance(&multi), 5);
+}
@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, IntoVal, Symbol,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+struct Fixture<'a> {
+    client: ScavengerContractClient<'a>,
+    token: TokenClient<'a>,
+    seller: Address,
+    buyer: Address,
+    batch_id: u64,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+
+    let seller = Address::generate(env);
+    let buyer = Address::generate(env);
+    client.register_participant(&seller, &ParticipantRole::Recycler, &symbol_short!("s"), &0, &0);
+    client.register_participant(&buyer, &ParticipantRole::Manufacturer, &symbol_short!("b"), &0, &0);
+    StellarAssetClient::new(env, &token).mint(&buyer, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let batch_id = client.create_material_batch(&seller, &MaterialKind::Metal, &5_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+
+    Fixture { client, token: TokenClient::new(env, &token), seller, buyer, batch_id }
+}
+
+fn last_topic(env: &Env) -> Symbol {
+    let (_, topics, _) = env.events().all().last().unwrap();
+    topics.get(0).unwrap().into_val(env)
+}
+
+#[test]
+fn test_offer_locks_buyer_tokens() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &400);
+
+    assert_eq!(f.token.balance(&f.buyer), 600);
+    assert_eq!(f.token.balance(&f.client.address), 400);
+    let escrow = f.client.get_escrow(&f.batch_id).unwrap();
+    assert_eq!(escrow.buyer, f.buyer);
+    assert_eq!(escrow.amount, 400);
+    assert_eq!(escrow.created_at, 200);
+    assert_eq!(last_topic(&env), Symbol::new(&env, "escrow_new"));
+}
+
+#[test]
+fn test_confirm_receipt_pays_seller_and_transfers_batch() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &400);
+
+    let batch = f.client.confirm_receipt(&f.batch_id);
+
+    assert_eq!(batch.owner, f.buyer);
+    assert_eq!(f.token.balance(&f.seller), 400);
+    assert_eq!(f.token.balance(&f.client.address), 0);
+    assert!(f.client.get_escrow(&f.batch_id).is_none());
+    assert_eq!(f.client.get_custody_chain(&f.batch_id).len(), 2);
+    assert_eq!(last_topic(&env), Symbol::new(&env, "escrow_paid"));
+}
+
+#[test]
+fn test_confirm_receipt_emits_transfer_with_batch_details() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &400);
+
+    f.client.confirm_receipt(&f.batch_id);
+
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("mat_xfer"), f.batch_id).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected)
+        .unwrap();
+    let (version, from, to, kind, weight): (u32, Address, Address, MaterialKind, u64) =
+        data.into_val(&env);
+    assert_eq!(version, 1);
+    assert_eq!((from, to), (f.seller, f.buyer));
+    assert_eq!((kind, weight), (MaterialKind::Metal, 5_000));
+}
+
+#[test]
+fn test_cancel_offer_refunds_buyer() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &400);
+
+    f.client.cancel_offer(&f.batch_id);
+
+    assert_eq!(f.token.balance(&f.buyer), 1_000);
+    assert!(f.client.get_escrow(&f.batch_id).is_none());
+    assert_eq!(f.client.get_material_batch(&f.batch_id).unwrap().owner, f.seller);
+    assert_eq!(last_topic(&env), Symbol::new(&env, "escrow_cancel"));
+    assert_eq!(f.client.try_confirm_receipt(&f.batch_id), Err(Ok(Error::EscrowNotFound)));
+}
+
+#[test]
+fn test_offer_payment_validation() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    assert_eq!(
+        f.client.try_offer_payment(&f.buyer, &f.batch_id, &f.token.address, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        f.client.try_offer_payment(&f.buyer, &99, &f.token.address, &1),
+        Err(Ok(Error::BatchNotFound))
+    );
+    assert_eq!(
+        f.client.try_offer_payment(&f.seller, &f.batch_id, &f.token.address, &1),
+        Err(Ok(Error::SameAddress))
+    );
+
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &1);
+    assert_eq!(
+        f.client.try_offer_payment(&f.buyer, &f.batch_id, &f.token.address, &1),
+        Err(Ok(Error::EscrowExists))
+    );
+}
+
+#[test]
+fn test_offer_rejects_recycled_batch() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.set_batch_status(&f.batch_id, &BatchStatus::Recycled);
+
+    assert_eq!(
+        f.client.try_offer_payment(&f.buyer, &f.batch_id, &f.token.address, &1),
+        Err(Ok(Error::BatchRecycled))
+    );
+}
+
+#[test]
+fn test_cancel_without_offer_fails() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    assert_eq!(f.client.try_cancel_offer(&f.batch_id), Err(Ok(Error::EscrowNotFound)));
+}
+
+#[test]
+#[should_panic]
+fn test_confirm_receipt_requires_auth() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.offer_payment(&f.buyer, &f.batch_id, &f.token.address, &400);
+
+    env.set_auths(&[]);
+    f.client.confirm_receipt(&f.batch_id);
+}
@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContractClient};
+
+fn register_at(client: &ScavengerContractClient, env: &Env, lat: i128, lon: i128) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Collector, &symbol_short!("p"), &lat, &lon);
+    address
+}
+
+#[test]
+fn test_geohash_reference_values() {
+    let env = Env::default();
+    let client = setup(&env);
+    let jutland = register_at(&client, &env, 57_649_110, 10_407_440);
+    let spain = register_at(&client, &env, 42_600_000, -5_600_000);
+
+    assert_eq!(client.geohash(&jutland, &10), String::from_str(&env, "u4pruydqqv"));
+    assert_eq!(client.geohash(&jutland, &1), String::from_str(&env, "u"));
+    assert_eq!(client.geohash(&spain, &5), String::from_str(&env, "ezs42"));
+}
+
+#[test]
+fn test_geohash_follows_location_updates() {
+    let env = Env::default();
+    let client = setup(&env);
+    let mover = register_at(&client, &env, 0, 0);
+    assert_eq!(client.geohash(&mover, &3), String::from_str(&env, "s00"));
+
+    client.update_participant_location(&mover, &42_600_000, &-5_600_000);
+    assert_eq!(client.geohash(&mover, &3), String::from_str(&env, "ezs"));
+}
+
+#[test]
+fn test_geohash_rejects_bad_precision() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register_at(&client, &env, 0, 0);
+
+    assert_eq!(client.try_geohash(&user, &0), Err(Ok(Error::InvalidPrecision)));
+    assert_eq!(client.try_geohash(&user, &11), Err(Ok(Error::InvalidPrecision)));
+}
+
+#[test]
+fn test_geohash_unknown_participant() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        client.try_geohash(&Address::generate(&env), &5),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
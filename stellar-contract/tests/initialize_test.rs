@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{Error, ScavengerContract, ScavengerContractClient};
+
+#[test]
+fn test_initialize_sets_admin_and_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_admin(), admin);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> = (symbol_short!("init"),).into_val(&env);
+    assert_eq!(topics, expected);
+    let emitted: Address = data.into_val(&env);
+    assert_eq!(emitted, admin);
+}
+
+#[test]
+fn test_initialize_twice_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.initialize(&admin);
+    assert_eq!(client.try_initialize(&other), Err(Ok(Error::AlreadyInitialized)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_initialize_after_initialize_admin_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize_admin(&admin);
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_initialize_admin_also_emits_init() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize_admin(&admin);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> = (symbol_short!("init"),).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_initialized_admin_can_call_privileged_functions() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.pause(&admin);
+    assert!(client.is_paused());
+}
+
+#[test]
+#[should_panic]
+fn test_initialize_requires_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    client.initialize(&Address::generate(&env));
+}
@@ -24,13 +24,6 @@ fn test_donate_zero_amount() {
         &0,
     );
     client.set_charity_contract(&admin, &charity);
-    client.register_participant(
-        &donor,
-        &ParticipantRole::Manufacturer,
-        &soroban_sdk::symbol_short!("Donor"),
-        &45_000_000,
-        &-93_000_000,
-    );
     client.donate_to_charity(&donor, &0);
 }
 
@@ -47,7 +40,7 @@ fn test_percentage_over_100() {
 }
 
 #[test]
-#[should_panic(expected = "Latitude must be between -90 and +90 degrees")]
+#[should_panic(expected = "Error(Contract, #13)")]
 fn test_invalid_latitude() {
     let env = Env::default();
     env.mock_all_auths();
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+mod common;
+
+use common::register;
+use soroban_sdk::Env;
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> ScavengerContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    env.budget().reset_unlimited();
+    client
+}
+
+#[test]
+fn test_list_participants_filters_by_role() {
+    let env = Env::default();
+    let client = setup(&env);
+    let c1 = register(&client, &env, ParticipantRole::Collector);
+    register(&client, &env, ParticipantRole::Recycler);
+    let c2 = register(&client, &env, ParticipantRole::Collector);
+
+    let collectors = client.list_participants(&ParticipantRole::Collector, &0, &10);
+    assert_eq!(collectors.len(), 2);
+    assert_eq!(collectors.get(0).unwrap().address, c1);
+    assert_eq!(collectors.get(1).unwrap().address, c2);
+
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 2);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 0);
+}
+
+#[test]
+fn test_list_participants_pages() {
+    let env = Env::default();
+    let client = setup(&env);
+    let mut all = soroban_sdk::Vec::new(&env);
+    for _ in 0..5 {
+        all.push_back(register(&client, &env, ParticipantRole::Recycler));
+    }
+
+    let page = client.list_participants(&ParticipantRole::Recycler, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().address, all.get(2).unwrap());
+    assert_eq!(page.get(1).unwrap().address, all.get(3).unwrap());
+
+    let tail = client.list_participants(&ParticipantRole::Recycler, &4, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().address, all.get(4).unwrap());
+}
+
+#[test]
+fn test_list_participants_out_of_range_start_is_empty() {
+    let env = Env::default();
+    let client = setup(&env);
+    register(&client, &env, ParticipantRole::Recycler);
+
+    assert_eq!(client.list_participants(&ParticipantRole::Recycler, &1, &10).len(), 0);
+    assert_eq!(client.list_participants(&ParticipantRole::Recycler, &u32::MAX, &u32::MAX).len(), 0);
+    assert_eq!(client.list_participants(&ParticipantRole::Collector, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_list_participants_caps_limit() {
+    let env = Env::default();
+    let client = setup(&env);
+    for _ in 0..55 {
+        register(&client, &env, ParticipantRole::Collector);
+    }
+
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 55);
+    assert_eq!(client.list_participants(&ParticipantRole::Collector, &0, &100).len(), 50);
+    assert_eq!(client.list_participants(&ParticipantRole::Collector, &50, &100).len(), 5);
+}
+
+#[test]
+fn test_list_participants_excludes_inactive() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = register(&client, &env, ParticipantRole::Manufacturer);
+    let b = register(&client, &env, ParticipantRole::Manufacturer);
+
+    client.deactivate_participant(&a);
+    let page = client.list_participants(&ParticipantRole::Manufacturer, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().address, b);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+}
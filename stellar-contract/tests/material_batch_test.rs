@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_create_material_batch_persists_record() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_234);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &2_500);
+    assert_eq!(id, 1);
+
+    let batch = client.get_material_batch(&id).unwrap();
+    assert_eq!(batch.owner, recycler);
+    assert_eq!(batch.kind, MaterialKind::Glass);
+    assert_eq!(batch.weight_grams, 2_500);
+    assert_eq!(batch.created_at, 1_234);
+}
+
+#[test]
+fn test_create_material_batch_ids_increment() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    assert_eq!(client.create_material_batch(&recycler, &MaterialKind::Plastic, &1), 1);
+    assert_eq!(client.create_material_batch(&recycler, &MaterialKind::EWaste, &1), 2);
+    assert_eq!(client.create_material_batch(&recycler, &MaterialKind::Paper, &1), 3);
+}
+
+#[test]
+fn test_create_material_batch_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &750);
+
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("mat_new"), id).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected)
+        .unwrap();
+    let (owner, kind, weight): (Address, MaterialKind, u64) = data.into_val(&env);
+    assert_eq!(owner, recycler);
+    assert_eq!(kind, MaterialKind::Metal);
+    assert_eq!(weight, 750);
+}
+
+#[test]
+fn test_create_material_batch_requires_recycler_role() {
+    let env = Env::default();
+    let client = setup(&env);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    assert_eq!(
+        client.try_create_material_batch(&collector, &MaterialKind::Plastic, &100),
+        Err(Ok(Error::NotRecycler))
+    );
+}
+
+#[test]
+fn test_create_material_batch_rejects_unregistered_and_inactive() {
+    let env = Env::default();
+    let client = setup(&env);
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_create_material_batch(&stranger, &MaterialKind::Plastic, &100),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    client.deactivate_participant(&recycler);
+    assert_eq!(
+        client.try_create_material_batch(&recycler, &MaterialKind::Plastic, &100),
+        Err(Ok(Error::Inactive))
+    );
+}
+
+#[test]
+fn test_create_material_batch_rejects_zero_weight() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    assert_eq!(
+        client.try_create_material_batch(&recycler, &MaterialKind::Plastic, &0),
+        Err(Ok(Error::InvalidWeight))
+    );
+}
+
+#[test]
+fn test_get_material_batch_unknown_id_is_none() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.get_material_batch(&42), None);
+}
+
+#[test]
+fn test_batch_weight_kg_display() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    for (grams, kg) in [(999, (0, 999)), (1_000, (1, 0)), (1_001, (1, 1))] {
+        let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &grams);
+        let batch = client.get_material_batch(&id).unwrap();
+        assert_eq!(batch.weight_grams, grams);
+        assert_eq!(batch.weight_kg(), kg);
+        assert_eq!(stellar_scavngr_contract::to_kg(grams), kg);
+    }
+}
@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Vec,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    (client, recycler)
+}
+
+#[test]
+fn test_merge_batches_sums_weight_and_deletes_inputs() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Glass, &400);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Glass, &600);
+    let c = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    let merged = client.merge_batches(&recycler, &vec![&env, a, b, c]);
+
+    let batch = client.get_material_batch(&merged).unwrap();
+    assert_eq!(batch.owner, recycler);
+    assert_eq!(batch.kind, MaterialKind::Glass);
+    assert_eq!(batch.weight_grams, 2_000);
+    assert_eq!(batch.created_at, 200);
+    for id in [a, b, c] {
+        assert!(client.get_material_batch(&id).is_none());
+    }
+}
+
+#[test]
+fn test_merged_batch_custody_keeps_creator() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Metal, &1);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Metal, &1);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    let merged = client.merge_batches(&recycler, &vec![&env, a, b]);
+
+    let chain = client.get_custody_chain(&merged);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.get(0).unwrap().holder, recycler);
+    assert_eq!(chain.get(0).unwrap().received_at, 100);
+    assert_eq!(chain.get(1).unwrap().holder, recycler);
+    assert_eq!(chain.get(1).unwrap().received_at, 200);
+    assert_eq!(client.get_batch_origins(&merged).len(), 1);
+    // The inputs' histories stay readable.
+    assert_eq!(client.get_custody_chain(&a).len(), 1);
+}
+
+#[test]
+fn test_merged_batch_keeps_every_creator() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Recycler, &symbol_short!("o"), &0, &0);
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    let b = client.create_material_batch(&other, &MaterialKind::Metal, &300);
+    env.ledger().with_mut(|li| li.timestamp = 160);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Metal, &100);
+    let c = client.create_material_batch(&recycler, &MaterialKind::Metal, &200);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    for id in [a, b, c] {
+        client.transfer_material(&id, &collector);
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    let merged = client.merge_batches(&collector, &vec![&env, a, b, c]);
+
+    let chain = client.get_custody_chain(&merged);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0).unwrap().holder, other);
+    assert_eq!(chain.get(0).unwrap().received_at, 150);
+    assert_eq!(chain.get(1).unwrap().holder, recycler);
+    assert_eq!(chain.get(1).unwrap().received_at, 160);
+    assert_eq!(chain.get(2).unwrap().holder, collector);
+    assert_eq!(chain.get(2).unwrap().received_at, 300);
+
+    let origins = client.get_batch_origins(&merged);
+    assert_eq!(origins.len(), 2);
+    assert_eq!(origins.get(0).unwrap().creator, recycler);
+    assert_eq!(origins.get(0).unwrap().weight_grams, 300);
+    assert_eq!(origins.get(1).unwrap().creator, other);
+    assert_eq!(origins.get(1).unwrap().weight_grams, 300);
+}
+
+#[test]
+fn test_merge_emits_event_with_consumed_ids() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Paper, &1);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Paper, &1);
+
+    let merged = client.merge_batches(&recycler, &vec![&env, a, b]);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("merged"), merged).into_val(&env);
+    assert_eq!(topics, expected);
+    let consumed: Vec<u64> = data.into_val(&env);
+    assert_eq!(consumed, vec![&env, a, b]);
+}
+
+#[test]
+fn test_merge_takes_most_advanced_status() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+    client.set_batch_status(&a, &BatchStatus::Processed);
+    client.set_batch_status(&b, &BatchStatus::InTransit);
+
+    let merged = client.merge_batches(&recycler, &vec![&env, a, b]);
+    assert_eq!(client.get_material_batch(&merged).unwrap().status, BatchStatus::Processed);
+}
+
+#[test]
+fn test_merge_rejects_mixed_kinds() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Glass, &1);
+
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a, b]),
+        Err(Ok(Error::KindMismatch))
+    );
+    assert!(client.get_material_batch(&a).is_some());
+}
+
+#[test]
+fn test_merge_requires_two_distinct_inputs() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a]),
+        Err(Ok(Error::TooFewBatches))
+    );
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a, a]),
+        Err(Ok(Error::TooFewBatches))
+    );
+}
+
+#[test]
+fn test_merge_requires_ownership_of_all_inputs() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Recycler, &symbol_short!("o"), &0, &0);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+    let b = client.create_material_batch(&other, &MaterialKind::Plastic, &1);
+
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a, b]),
+        Err(Ok(Error::NotBatchOwner))
+    );
+}
+
+#[test]
+fn test_merge_rejects_missing_and_recycled_inputs() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+    let b = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1);
+
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a, 99]),
+        Err(Ok(Error::BatchNotFound))
+    );
+
+    client.set_batch_status(&b, &BatchStatus::Recycled);
+    assert_eq!(
+        client.try_merge_batches(&recycler, &vec![&env, a, b]),
+        Err(Ok(Error::BatchRecycled))
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_register_wraps_single_role() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+
+    let p = client.get_participant(&user).unwrap();
+    assert_eq!(p.role, ParticipantRole::Collector);
+    assert_eq!(p.roles, ParticipantRole::Collector.bit());
+    assert!(p.has_role(ParticipantRole::Collector));
+    assert!(!p.has_role(ParticipantRole::Manufacturer));
+}
+
+#[test]
+fn test_add_role_lists_participant_under_each_role() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+
+    let p = client.add_role(&user, &ParticipantRole::Manufacturer);
+    assert!(p.has_role(ParticipantRole::Collector));
+    assert!(p.has_role(ParticipantRole::Manufacturer));
+    assert_eq!(p.role, ParticipantRole::Collector);
+
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+    assert!(client.can_collect(&user));
+    assert!(client.can_manufacture(&user));
+
+    // Adding again is a no-op.
+    client.add_role(&user, &ParticipantRole::Manufacturer);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+}
+
+#[test]
+fn test_added_role_grants_capabilities() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+
+    assert_eq!(
+        client.try_create_material_batch(&user, &MaterialKind::Plastic, &100),
+        Err(Ok(Error::NotRecycler))
+    );
+    client.add_role(&user, &ParticipantRole::Recycler);
+    assert_eq!(client.create_material_batch(&user, &MaterialKind::Plastic, &100), 1);
+}
+
+#[test]
+fn test_remove_role_updates_indexes_and_primary() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+    client.add_role(&user, &ParticipantRole::Manufacturer);
+
+    let p = client.remove_role(&user, &ParticipantRole::Collector);
+    assert!(!p.has_role(ParticipantRole::Collector));
+    assert_eq!(p.role, ParticipantRole::Manufacturer);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+}
+
+#[test]
+fn test_remove_last_role_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Recycler);
+
+    assert_eq!(
+        client.try_remove_role(&user, &ParticipantRole::Recycler),
+        Err(Ok(Error::LastRole))
+    );
+    // Removing a role that isn't held is a no-op.
+    assert_eq!(client.remove_role(&user, &ParticipantRole::Collector).roles, ParticipantRole::Recycler.bit());
+}
+
+#[test]
+fn test_deactivation_drops_every_role_index() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+    client.add_role(&user, &ParticipantRole::Manufacturer);
+
+    client.deactivate_participant(&user);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 0);
+
+    client.reactivate_participant(&user);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+}
+
+#[test]
+fn test_update_role_replaces_role_set() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+    client.add_role(&user, &ParticipantRole::Manufacturer);
+
+    let p = client.update_role(&user, &ParticipantRole::Recycler);
+    assert_eq!(p.roles, ParticipantRole::Recycler.bit());
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 1);
+}
+
+#[test]
+fn test_multi_role_flow_check() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_enforce_role_flow(&admin, &true);
+
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let other_recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+
+    assert_eq!(
+        client.try_transfer_material(&id, &other_recycler),
+        Err(Ok(Error::IllegalFlow))
+    );
+    client.add_role(&other_recycler, &ParticipantRole::Collector);
+    assert_eq!(client.transfer_material(&id, &other_recycler).owner, other_recycler);
+}
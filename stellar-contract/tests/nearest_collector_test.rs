@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContractClient};
+
+fn register_at(
+    client: &ScavengerContractClient,
+    env: &Env,
+    role: ParticipantRole,
+    latitude: i128,
+    longitude: i128,
+) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &symbol_short!("p"), &latitude, &longitude);
+    address
+}
+
+#[test]
+fn test_nearest_collector_picks_closest() {
+    let env = Env::default();
+    let client = setup(&env);
+    // Recycler in London; collectors in Paris, Manchester and New York.
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 51_507_400, -127_800);
+    register_at(&client, &env, ParticipantRole::Collector, 48_856_600, 2_352_200);
+    let manchester = register_at(&client, &env, ParticipantRole::Collector, 53_480_800, -2_242_600);
+    register_at(&client, &env, ParticipantRole::Collector, 40_712_800, -74_006_000);
+
+    assert_eq!(client.nearest_collector(&recycler), Some(manchester));
+}
+
+#[test]
+fn test_nearest_collector_ignores_other_roles() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    register_at(&client, &env, ParticipantRole::Manufacturer, 0, 0);
+    register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let far = register_at(&client, &env, ParticipantRole::Collector, 10_000_000, 10_000_000);
+
+    assert_eq!(client.nearest_collector(&recycler), Some(far));
+}
+
+#[test]
+fn test_nearest_collector_skips_inactive_and_deregistered() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let closest = register_at(&client, &env, ParticipantRole::Collector, 100_000, 0);
+    let middle = register_at(&client, &env, ParticipantRole::Collector, 200_000, 0);
+    let farthest = register_at(&client, &env, ParticipantRole::Collector, 300_000, 0);
+
+    client.deactivate_participant(&closest);
+    client.deregister_participant(&middle);
+    assert_eq!(client.nearest_collector(&recycler), Some(farthest.clone()));
+
+    client.reactivate_participant(&closest);
+    assert_eq!(client.nearest_collector(&recycler), Some(closest));
+}
+
+#[test]
+fn test_nearest_collector_follows_role_changes() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let collector = register_at(&client, &env, ParticipantRole::Collector, 100_000, 0);
+
+    client.update_role(&collector, &ParticipantRole::Manufacturer);
+    assert_eq!(client.nearest_collector(&recycler), None);
+
+    let manufacturer = register_at(&client, &env, ParticipantRole::Manufacturer, 50_000, 0);
+    client.update_role(&manufacturer, &ParticipantRole::Collector);
+    assert_eq!(client.nearest_collector(&recycler), Some(manufacturer));
+}
+
+#[test]
+fn test_nearest_collector_none_when_no_collectors() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+
+    assert_eq!(client.nearest_collector(&recycler), None);
+}
+
+#[test]
+fn test_nearest_collector_unknown_recycler() {
+    let env = Env::default();
+    let client = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_nearest_collector(&stranger),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
+
+/// Give `collector` a reputation of `delta` by handing it a batch from
+/// `recycler` and having `recycler` rate it.
+fn rate(client: &ScavengerContractClient, env: &Env, recycler: &Address, collector: &Address, delta: i32) {
+    use soroban_sdk::testutils::Ledger;
+    use stellar_scavngr_contract::MaterialKind;
+
+    let id = client.create_material_batch(recycler, &MaterialKind::Plastic, &1_000);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.transfer_material(&id, collector);
+    client.rate_participant(recycler, collector, &delta);
+}
+
+#[test]
+fn test_best_collector_prefers_reputation_over_distance() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let near = register_at(&client, &env, ParticipantRole::Collector, 100_000, 0);
+    let trusted = register_at(&client, &env, ParticipantRole::Collector, 300_000, 0);
+    rate(&client, &env, &recycler, &near, 1);
+    rate(&client, &env, &recycler, &trusted, 4);
+
+    assert_eq!(client.best_collector(&recycler, &50_000), Some(trusted));
+    assert_eq!(client.nearest_collector(&recycler), Some(near));
+}
+
+#[test]
+fn test_best_collector_filters_by_distance() {
+    let env = Env::default();
+    let client = setup(&env);
+    // One degree of latitude is ~111 km
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let near = register_at(&client, &env, ParticipantRole::Collector, 100_000, 0);
+    let far = register_at(&client, &env, ParticipantRole::Collector, 1_000_000, 0);
+    rate(&client, &env, &recycler, &far, 5);
+
+    assert_eq!(client.best_collector(&recycler, &20_000), Some(near));
+    assert_eq!(client.best_collector(&recycler, &200_000), Some(far));
+    assert_eq!(client.best_collector(&recycler, &1_000), None);
+}
+
+#[test]
+fn test_best_collector_ties_break_by_distance_then_registration() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let first = register_at(&client, &env, ParticipantRole::Collector, 0, 200_000);
+    let second = register_at(&client, &env, ParticipantRole::Collector, 200_000, 0);
+    let closer = register_at(&client, &env, ParticipantRole::Collector, 300_000, 0);
+    rate(&client, &env, &recycler, &first, 2);
+    rate(&client, &env, &recycler, &second, 2);
+    rate(&client, &env, &recycler, &closer, 2);
+
+    // `first` and `second` are equally reputable and equally far; `closer` moves away.
+    client.update_participant_location(&closer, &400_000, &0);
+    assert_eq!(client.best_collector(&recycler, &100_000), Some(first.clone()));
+
+    client.update_participant_location(&closer, &100_000, &0);
+    assert_eq!(client.best_collector(&recycler, &100_000), Some(closer));
+}
+
+#[test]
+fn test_best_collector_unknown_recycler() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        client.try_best_collector(&Address::generate(&env), &1_000),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
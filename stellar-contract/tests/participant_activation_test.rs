@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Vec,
+};
+use stellar_scavngr_contract::{
+    Error, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+#[test]
+fn test_registration_defaults_to_active() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Recycler);
+
+    assert!(client.get_participant(&user).unwrap().active);
+}
+
+#[test]
+fn test_deactivate_keeps_record_readable() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Recycler);
+
+    let updated = client.deactivate_participant(&user);
+    assert!(!updated.active);
+    assert!(updated.is_registered);
+
+    let stored = client.get_participant(&user).unwrap();
+    assert!(!stored.active);
+    assert!(client.is_participant_registered(&user));
+}
+
+#[test]
+fn test_deactivate_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+
+    client.deactivate_participant(&user);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("deact"), user.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_reactivate_restores_active() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Collector);
+
+    client.deactivate_participant(&user);
+    let updated = client.reactivate_participant(&user);
+    assert!(updated.active);
+    assert!(client.get_participant(&user).unwrap().active);
+}
+
+#[test]
+fn test_deactivate_unknown_address_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_deactivate_participant(&stranger),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+    assert_eq!(
+        client.try_reactivate_participant(&stranger),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
+
+#[test]
+fn test_deactivate_deregistered_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = register(&client, &env, ParticipantRole::Recycler);
+    client.deregister_participant(&user);
+
+    assert_eq!(
+        client.try_deactivate_participant(&user),
+        Err(Ok(Error::NotRegistered))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_deactivate_requires_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+
+    env.set_auths(&[]);
+    client.deactivate_participant(&user);
+}
+
+#[test]
+fn test_transfer_from_inactive_sender_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    client.deactivate_participant(&recycler);
+
+    assert_eq!(
+        client.try_transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0),
+        Err(Ok(Error::Inactive))
+    );
+}
+
+#[test]
+fn test_transfer_to_inactive_recipient_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    client.deactivate_participant(&collector);
+    assert_eq!(
+        client.try_transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0),
+        Err(Ok(Error::Inactive))
+    );
+
+    client.reactivate_participant(&collector);
+    let transfer = client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    assert_eq!(transfer.to, collector);
+}
+
+#[test]
+fn test_batch_transfer_to_inactive_recipient_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    client.deactivate_participant(&collector);
+
+    let mut ids = Vec::new(&env);
+    ids.push_back(waste_id);
+    assert_eq!(
+        client.try_batch_transfer_waste(&ids, &collector, &0, &0),
+        Err(Ok(Error::Inactive))
+    );
+}
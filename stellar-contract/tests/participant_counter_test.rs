@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup_with_admin};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{Error, ParticipantRole};
+
+#[test]
+fn test_total_participants_counts_registrations() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    assert_eq!(client.total_participants(), 0);
+
+    register(&client, &env, ParticipantRole::Recycler);
+    register(&client, &env, ParticipantRole::Collector);
+    register(&client, &env, ParticipantRole::Collector);
+
+    assert_eq!(client.total_participants(), 3);
+}
+
+#[test]
+fn test_deactivate_and_deregister_keep_total() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let a = register(&client, &env, ParticipantRole::Collector);
+    let b = register(&client, &env, ParticipantRole::Collector);
+
+    client.deactivate_participant(&a);
+    client.deregister_participant(&b);
+
+    assert_eq!(client.total_participants(), 2);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+
+    client.reactivate_participant(&a);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 1);
+}
+
+#[test]
+fn test_remove_participant_decrements_total() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let a = register(&client, &env, ParticipantRole::Recycler);
+    register(&client, &env, ParticipantRole::Recycler);
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &a);
+
+    assert_eq!(client.total_participants(), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 1);
+}
+
+#[test]
+fn test_removing_unknown_address_does_not_decrement() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(
+        client.try_remove_participant(&admin, &Address::generate(&env)),
+        Err(Ok(Error::NotRegistered))
+    );
+    assert_eq!(client.total_participants(), 0);
+
+    let a = register(&client, &env, ParticipantRole::Recycler);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &a);
+    assert_eq!(
+        client.try_remove_participant(&admin, &a),
+        Err(Ok(Error::NotRegistered))
+    );
+    assert_eq!(client.total_participants(), 0);
+}
+
+#[test]
+fn test_role_counts_follow_role_changes() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let a = register(&client, &env, ParticipantRole::Recycler);
+
+    client.add_role(&a, &ParticipantRole::Collector);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 1);
+
+    client.update_role(&a, &ParticipantRole::Manufacturer);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+    assert_eq!(client.total_participants(), 1);
+}
@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_with_admin;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, ParticipantRole, DEFAULT_TTL_LEDGERS};
+
+#[test]
+fn test_ttl_defaults_and_can_be_changed() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(client.get_ttl(), DEFAULT_TTL_LEDGERS);
+    client.set_ttl(&admin, &100_000);
+    assert_eq!(client.get_ttl(), 100_000);
+}
+
+#[test]
+fn test_set_ttl_rejects_zero_and_above_network_max() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(client.try_set_ttl(&admin, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_set_ttl(&admin, &u32::MAX), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.get_ttl(), DEFAULT_TTL_LEDGERS);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_ttl_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    client.set_ttl(&Address::generate(&env), &100_000);
+}
+
+#[test]
+fn test_bump_participant_ttl() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.try_bump_participant_ttl(&user), Err(Ok(Error::ParticipantNotFound)));
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("al"), &0, &0);
+    client.bump_participant_ttl(&user);
+}
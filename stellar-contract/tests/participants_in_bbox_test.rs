@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> ScavengerContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    env.budget().reset_unlimited();
+    client
+}
+
+fn register_at(
+    client: &ScavengerContractClient,
+    env: &Env,
+    role: ParticipantRole,
+    latitude: i128,
+    longitude: i128,
+) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &symbol_short!("p"), &latitude, &longitude);
+    address
+}
+
+#[test]
+fn test_bbox_returns_participants_inside_in_index_order() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = register_at(&client, &env, ParticipantRole::Recycler, 10_000_000, 10_000_000);
+    register_at(&client, &env, ParticipantRole::Recycler, 30_000_000, 10_000_000);
+    let b = register_at(&client, &env, ParticipantRole::Collector, 15_000_000, 20_000_000);
+    // On the boundary counts as inside.
+    let c = register_at(&client, &env, ParticipantRole::Manufacturer, 20_000_000, 0);
+
+    let found = client.participants_in_bbox(&0, &0, &20_000_000, &20_000_000, &None);
+    assert_eq!(found.len(), 3);
+    assert_eq!(found.get(0).unwrap(), a);
+    assert_eq!(found.get(1).unwrap(), b);
+    assert_eq!(found.get(2).unwrap(), c);
+}
+
+#[test]
+fn test_bbox_filters_by_role() {
+    let env = Env::default();
+    let client = setup(&env);
+    register_at(&client, &env, ParticipantRole::Recycler, 1_000_000, 1_000_000);
+    let collector = register_at(&client, &env, ParticipantRole::Collector, 1_000_000, 1_000_000);
+
+    let found = client.participants_in_bbox(
+        &0,
+        &0,
+        &2_000_000,
+        &2_000_000,
+        &Some(ParticipantRole::Collector),
+    );
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap(), collector);
+}
+
+#[test]
+fn test_bbox_wraps_across_antimeridian() {
+    let env = Env::default();
+    let client = setup(&env);
+    let east = register_at(&client, &env, ParticipantRole::Collector, 0, 179_000_000);
+    let west = register_at(&client, &env, ParticipantRole::Collector, 0, -179_000_000);
+    register_at(&client, &env, ParticipantRole::Collector, 0, 0);
+
+    let found = client.participants_in_bbox(&-1_000_000, &178_000_000, &1_000_000, &-178_000_000, &None);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.get(0).unwrap(), east);
+    assert_eq!(found.get(1).unwrap(), west);
+}
+
+#[test]
+fn test_bbox_skips_inactive_and_deregistered() {
+    let env = Env::default();
+    let client = setup(&env);
+    let inactive = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let gone = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    let active = register_at(&client, &env, ParticipantRole::Recycler, 0, 0);
+    client.deactivate_participant(&inactive);
+    client.deregister_participant(&gone);
+
+    let found = client.participants_in_bbox(&-1, &-1, &1, &1, &None);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap(), active);
+}
+
+#[test]
+fn test_bbox_caps_results() {
+    let env = Env::default();
+    let client = setup(&env);
+    for _ in 0..105 {
+        register_at(&client, &env, ParticipantRole::Collector, 0, 0);
+    }
+
+    assert_eq!(client.participants_in_bbox(&-1, &-1, &1, &1, &None).len(), 100);
+}
+
+#[test]
+fn test_bbox_rejects_inverted_latitude_and_out_of_range_bounds() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        client.try_participants_in_bbox(&10, &0, &-10, &0, &None),
+        Err(Ok(Error::InvalidCoordinates))
+    );
+    assert_eq!(
+        client.try_participants_in_bbox(&0, &0, &90_000_001, &0, &None),
+        Err(Ok(Error::InvalidCoordinates))
+    );
+}
@@ -1,7 +1,9 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::{Address as _, Events}, Address, Env, IntoVal, String};
-use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+use soroban_sdk::{symbol_short, testutils::{Address as _, Events, Ledger}, Address, Env, IntoVal, String};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
 
 fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
     let contract_id = env.register_contract(None, ScavengerContract);
@@ -39,7 +41,7 @@ fn test_non_admin_cannot_unpause() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_register_participant() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -49,7 +51,7 @@ fn test_pause_blocks_register_participant() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_submit_material() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -60,7 +62,7 @@ fn test_pause_blocks_submit_material() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_transfer_waste() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -127,7 +129,7 @@ fn test_unpause_emits_event() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_recycle_waste() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -138,7 +140,7 @@ fn test_pause_blocks_recycle_waste() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_transfer_waste_v2() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -152,7 +154,7 @@ fn test_pause_blocks_transfer_waste_v2() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_confirm_waste_details() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -167,7 +169,7 @@ fn test_pause_blocks_confirm_waste_details() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_create_incentive() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -178,7 +180,7 @@ fn test_pause_blocks_create_incentive() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_deactivate_incentive() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -190,7 +192,7 @@ fn test_pause_blocks_deactivate_incentive() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_update_role() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -201,7 +203,7 @@ fn test_pause_blocks_update_role() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_deregister_participant() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -212,7 +214,7 @@ fn test_pause_blocks_deregister_participant() {
 }
 
 #[test]
-#[should_panic(expected = "Contract is paused")]
+#[should_panic(expected = "Error(Contract, #37)")]
 fn test_pause_blocks_donate_to_charity() {
     let env = Env::default();
     let (client, admin) = setup(&env);
@@ -236,3 +238,61 @@ fn test_read_functions_work_while_paused() {
     assert!(client.is_participant_registered(&user));
     assert!(client.get_participant(&user).is_some());
 }
+
+#[test]
+fn test_pause_returns_typed_error_from_result_entry_points() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let recycler = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("R"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("C"), &0, &0);
+    let batch_id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+
+    client.pause(&admin);
+
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_register_participant(&late, &ParticipantRole::Recycler, &symbol_short!("L"), &0, &0),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(
+        client.try_create_material_batch(&recycler, &MaterialKind::Plastic, &1_000),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(
+        client.try_transfer_material(&batch_id, &collector),
+        Err(Ok(Error::Paused))
+    );
+
+    client.unpause(&admin);
+    assert_eq!(client.transfer_material(&batch_id, &collector).owner, collector);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+fn test_pause_blocks_update_participant_location() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("C"), &0, &0);
+    client.pause(&admin);
+    client.update_participant_location(&user, &1_000_000, &1_000_000);
+}
+
+#[test]
+fn test_reads_work_while_paused() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("R"), &0, &0);
+    let batch_id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+
+    client.pause(&admin);
+
+    assert!(client.get_participant(&recycler).is_some());
+    assert!(client.get_material_batch(&batch_id).is_some());
+    assert_eq!(client.get_custody_chain(&batch_id).len(), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 1);
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let price_admin = Address::generate(env);
+    client.set_price_admin(&admin, &price_admin);
+    (client, admin, price_admin)
+}
+
+fn batch(client: &ScavengerContractClient, env: &Env, kind: MaterialKind, weight: u64) -> u64 {
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.create_material_batch(&recycler, &kind, &weight)
+}
+
+#[test]
+fn test_estimate_value_uses_current_price() {
+    let env = Env::default();
+    let (client, _, price_admin) = setup(&env);
+    let id = batch(&client, &env, MaterialKind::Metal, 2_500);
+
+    client.set_price(&price_admin, &MaterialKind::Metal, &4);
+    assert_eq!(client.estimate_value(&id), 10_000);
+
+    client.set_price(&price_admin, &MaterialKind::Metal, &6);
+    assert_eq!(client.get_price(&MaterialKind::Metal), Some(6));
+    assert_eq!(client.estimate_value(&id), 15_000);
+}
+
+#[test]
+fn test_estimate_value_without_price_fails() {
+    let env = Env::default();
+    let (client, _, price_admin) = setup(&env);
+    let id = batch(&client, &env, MaterialKind::Paper, 1_000);
+    client.set_price(&price_admin, &MaterialKind::Glass, &1);
+
+    assert_eq!(client.try_estimate_value(&id), Err(Ok(Error::NoPrice)));
+    assert_eq!(client.try_estimate_value(&99), Err(Ok(Error::BatchNotFound)));
+}
+
+#[test]
+fn test_set_price_emits_event() {
+    let env = Env::default();
+    let (client, _, price_admin) = setup(&env);
+
+    client.set_price(&price_admin, &MaterialKind::Plastic, &7);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("price"), MaterialKind::Plastic).into_val(&env);
+    assert_eq!(topics, expected);
+    let price: i128 = data.into_val(&env);
+    assert_eq!(price, 7);
+}
+
+#[test]
+fn test_set_price_rejects_non_positive() {
+    let env = Env::default();
+    let (client, _, price_admin) = setup(&env);
+
+    assert_eq!(
+        client.try_set_price(&price_admin, &MaterialKind::Glass, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_price(&price_admin, &MaterialKind::Glass, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(client.get_price(&MaterialKind::Glass), None);
+}
+
+#[test]
+fn test_set_price_requires_price_admin() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_price(&admin, &MaterialKind::Glass, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_price(&MaterialKind::Glass), None);
+}
+
+#[test]
+fn test_set_price_without_price_admin_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_price(&caller, &MaterialKind::Glass, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_price_admin_requires_admin() {
+    let env = Env::default();
+    let (client, _, price_admin) = setup(&env);
+
+    client.set_price_admin(&price_admin, &price_admin);
+}
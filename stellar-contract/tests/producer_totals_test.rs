@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::{Address as _, Ledger}, Address, Env};
+use stellar_scavngr_contract::{MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    (client, recycler)
+}
+
+#[test]
+fn test_totals_start_at_zero() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    assert_eq!(client.get_total_weight(&recycler), 0);
+    assert_eq!(client.get_total_batches(&recycler), 0);
+}
+
+#[test]
+fn test_totals_accumulate_per_creator() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Recycler, &symbol_short!("o"), &0, &0);
+
+    client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_200);
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &800);
+    client.create_material_batch(&other, &MaterialKind::Metal, &50);
+
+    assert_eq!(client.get_total_weight(&recycler), 2_000);
+    assert_eq!(client.get_total_batches(&recycler), 2);
+    assert_eq!(client.get_total_weight(&other), 50);
+    assert_eq!(client.get_total_batches(&other), 1);
+}
+
+#[test]
+fn test_totals_unchanged_by_transfer() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &500);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.transfer_material(&id, &collector);
+
+    assert_eq!(client.get_total_weight(&recycler), 500);
+    assert_eq!(client.get_total_weight(&collector), 0);
+    assert_eq!(client.get_total_batches(&collector), 0);
+}
+
+#[test]
+fn test_remove_participant_zeroes_totals() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    client.create_material_batch(&recycler, &MaterialKind::EWaste, &3_000);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &recycler);
+
+    assert_eq!(client.get_total_weight(&recycler), 0);
+    assert_eq!(client.get_total_batches(&recycler), 0);
+    // The custodian does not inherit the removed creator's output.
+    assert_eq!(client.get_total_weight(&admin), 0);
+
+    // Re-registering starts from a clean slate.
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.create_material_batch(&recycler, &MaterialKind::EWaste, &100);
+    assert_eq!(client.get_total_weight(&recycler), 100);
+    assert_eq!(client.get_total_batches(&recycler), 1);
+}
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+const DAY: u64 = 86_400;
+
+/// Returns a client plus a recycler and collector that have traded a batch.
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = DAY + 1);
+    client.transfer_material(&id, &collector);
+
+    (client, recycler, collector)
+}
+
+fn next_day(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+}
+
+#[test]
+fn test_reputation_starts_at_zero() {
+    let env = Env::default();
+    let (client, recycler, _) = setup(&env);
+
+    assert_eq!(client.get_participant(&recycler).unwrap().reputation, 0);
+}
+
+#[test]
+fn test_rate_participant_in_both_directions() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    assert_eq!(client.rate_participant(&collector, &recycler, &3), 3);
+    assert_eq!(client.rate_participant(&recycler, &collector, &-2), -2);
+    assert_eq!(client.get_participant(&recycler).unwrap().reputation, 3);
+    assert_eq!(client.get_participant(&collector).unwrap().reputation, -2);
+}
+
+#[test]
+fn test_rate_participant_emits_event() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    client.rate_participant(&collector, &recycler, &4);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("rated"), recycler.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let (rater, score): (Address, i32) = data.into_val(&env);
+    assert_eq!(rater, collector);
+    assert_eq!(score, 4);
+}
+
+#[test]
+fn test_rate_participant_clamps_delta() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    assert_eq!(client.rate_participant(&collector, &recycler, &100), 5);
+    next_day(&env);
+    assert_eq!(client.rate_participant(&collector, &recycler, &-100), 0);
+}
+
+#[test]
+fn test_rate_participant_once_per_day() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    client.rate_participant(&collector, &recycler, &1);
+    assert_eq!(
+        client.try_rate_participant(&collector, &recycler, &1),
+        Err(Ok(Error::AlreadyRated))
+    );
+
+    next_day(&env);
+    assert_eq!(client.rate_participant(&collector, &recycler, &1), 2);
+}
+
+#[test]
+fn test_rate_participant_rejects_self() {
+    let env = Env::default();
+    let (client, recycler, _) = setup(&env);
+
+    assert_eq!(
+        client.try_rate_participant(&recycler, &recycler, &5),
+        Err(Ok(Error::SameAddress))
+    );
+}
+
+#[test]
+fn test_rate_participant_requires_custody_relationship() {
+    let env = Env::default();
+    let (client, recycler, _) = setup(&env);
+    let stranger = Address::generate(&env);
+    client.register_participant(&stranger, &ParticipantRole::Manufacturer, &symbol_short!("m"), &0, &0);
+
+    assert_eq!(
+        client.try_rate_participant(&stranger, &recycler, &-5),
+        Err(Ok(Error::NotCounterparty))
+    );
+}
+
+#[test]
+fn test_rate_unknown_target_rejected() {
+    let env = Env::default();
+    let (client, recycler, _) = setup(&env);
+
+    assert_eq!(
+        client.try_rate_participant(&recycler, &Address::generate(&env), &1),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
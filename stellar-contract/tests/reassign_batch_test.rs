@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{advance, register, setup_with_admin};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, String, Symbol,
+};
+use stellar_scavngr_contract::{BatchStatus, Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_reassign_batch_moves_ownership_and_marks_custody() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let rescuer = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    advance(&env);
+    client.transfer_material(&id, &collector);
+
+    advance(&env);
+    let batch = client.reassign_batch(&admin, &id, &rescuer);
+
+    assert_eq!(batch.owner, rescuer);
+    assert_eq!(client.get_material_batch(&id).unwrap().owner, rescuer);
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(2).unwrap().holder, rescuer);
+    assert_eq!(client.get_admin_custody(&id), vec![&env, 2]);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (Symbol::new(&env, "admin_xfer"), id).into_val(&env);
+    assert_eq!(topics, expected);
+    let (by, from, to): (Address, Address, Address) = data.into_val(&env);
+    assert_eq!((by, from, to), (admin, collector, rescuer));
+}
+
+#[test]
+fn test_reassign_disputed_batch() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let rescuer = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    client.flag_batch(&recycler, &id, &String::from_str(&env, "lost key"));
+
+    advance(&env);
+    assert_eq!(client.try_transfer_material(&id, &rescuer), Err(Ok(Error::BatchDisputed)));
+    assert_eq!(client.reassign_batch(&admin, &id, &rescuer).owner, rescuer);
+
+    // The dispute stays open with the new owner
+    assert!(client.get_dispute(&id).is_some());
+}
+
+#[test]
+fn test_reassign_bypasses_flow_checks() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_role_flow(&admin, &true);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let other_recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+
+    advance(&env);
+    assert_eq!(client.reassign_batch(&admin, &id, &other_recycler).owner, other_recycler);
+}
+
+#[test]
+fn test_reassign_rejects_recycled_and_bad_recipients() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let inactive = register(&client, &env, ParticipantRole::Collector);
+    client.deactivate_participant(&inactive);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &1_000);
+    advance(&env);
+
+    assert_eq!(client.try_reassign_batch(&admin, &id, &recycler), Err(Ok(Error::SameAddress)));
+    assert_eq!(client.try_reassign_batch(&admin, &id, &inactive), Err(Ok(Error::Inactive)));
+    assert_eq!(
+        client.try_reassign_batch(&admin, &id, &Address::generate(&env)),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+    assert_eq!(client.try_reassign_batch(&admin, &99, &recycler), Err(Ok(Error::BatchNotFound)));
+
+    client.set_batch_status(&id, &BatchStatus::Recycled);
+    client.reactivate_participant(&inactive);
+    assert_eq!(client.try_reassign_batch(&admin, &id, &inactive), Err(Ok(Error::BatchRecycled)));
+}
+
+#[test]
+fn test_split_keeps_admin_custody_marks() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let rescuer = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    advance(&env);
+    client.reassign_batch(&admin, &id, &rescuer);
+
+    advance(&env);
+    let children = client.split_batch(&id, &vec![&env, 400, 600]);
+    for child in children.iter() {
+        assert_eq!(client.get_admin_custody(&child), vec![&env, 1]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_reassign_batch_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let thief = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+
+    client.reassign_batch(&thief, &id, &thief);
+}
+
+#[test]
+fn test_reassign_batch_in_same_ledger_as_transfer() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let rescuer = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    advance(&env);
+    client.transfer_material(&id, &collector);
+
+    client.reassign_batch(&admin, &id, &rescuer);
+    client.transfer_material(&id, &manufacturer);
+
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 4);
+    assert_eq!(chain.get(2).unwrap().holder, rescuer);
+    assert_eq!(chain.get(3).unwrap().holder, manufacturer);
+    assert_eq!(chain.get(1).unwrap().received_at, chain.get(3).unwrap().received_at);
+}
@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+/// Client, admin, reward token and a recycler holding 1_000 points.
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, TokenClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    client.set_token_address(&admin, &token);
+    StellarAssetClient::new(env, &token).mint(&contract_id, &50);
+
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.create_material_batch(&recycler, &MaterialKind::Paper, &100_000);
+    assert_eq!(client.get_points(&recycler), 1_000);
+
+    (client, admin, TokenClient::new(env, &token), recycler)
+}
+
+#[test]
+fn test_redeem_burns_points_and_pays_tokens() {
+    let env = Env::default();
+    let (client, _, token, recycler) = setup(&env);
+
+    assert_eq!(client.redeem_points(&recycler, &500), 5);
+
+    assert_eq!(client.get_points(&recycler), 500);
+    assert_eq!(token.balance(&recycler), 5);
+    assert_eq!(token.balance(&client.address), 45);
+}
+
+#[test]
+fn test_redeem_keeps_remainder_below_rate() {
+    let env = Env::default();
+    let (client, _, token, recycler) = setup(&env);
+
+    assert_eq!(client.redeem_points(&recycler, &250), 2);
+    assert_eq!(client.get_points(&recycler), 800);
+    assert_eq!(token.balance(&recycler), 2);
+}
+
+#[test]
+fn test_redeem_emits_event() {
+    let env = Env::default();
+    let (client, _, _, recycler) = setup(&env);
+
+    client.redeem_points(&recycler, &300);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("redeem"), recycler.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let (points, tokens): (u64, i128) = data.into_val(&env);
+    assert_eq!((points, tokens), (300, 3));
+}
+
+#[test]
+fn test_rate_is_tunable_by_admin() {
+    let env = Env::default();
+    let (client, admin, token, recycler) = setup(&env);
+    assert_eq!(client.get_points_per_token(), 100);
+
+    client.set_points_per_token(&admin, &25);
+    assert_eq!(client.redeem_points(&recycler, &100), 4);
+    assert_eq!(token.balance(&recycler), 4);
+
+    assert_eq!(client.try_set_points_per_token(&admin, &0), Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_redeem_more_than_balance_rejected() {
+    let env = Env::default();
+    let (client, _, _, recycler) = setup(&env);
+
+    assert_eq!(
+        client.try_redeem_points(&recycler, &1_001),
+        Err(Ok(Error::InsufficientPoints))
+    );
+    assert_eq!(client.get_points(&recycler), 1_000);
+}
+
+#[test]
+fn test_redeem_less_than_one_token_rejected() {
+    let env = Env::default();
+    let (client, _, _, recycler) = setup(&env);
+
+    assert_eq!(client.try_redeem_points(&recycler, &99), Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_redeem_beyond_contract_balance_rejected() {
+    let env = Env::default();
+    let (client, admin, token, recycler) = setup(&env);
+    client.set_points_per_token(&admin, &10);
+
+    // 1_000 points would buy 100 tokens but the pool only holds 50.
+    assert_eq!(
+        client.try_redeem_points(&recycler, &1_000),
+        Err(Ok(Error::InsufficientRewardPool))
+    );
+    assert_eq!(client.get_points(&recycler), 1_000);
+    assert_eq!(token.balance(&client.address), 50);
+}
+
+#[test]
+fn test_redeem_cannot_spend_escrowed_tokens() {
+    let env = Env::default();
+    let (client, admin, token, recycler) = setup(&env);
+    client.set_points_per_token(&admin, &10);
+
+    // A buyer locks 40 of the reward token against the recycler's batch.
+    let buyer = Address::generate(&env);
+    client.register_participant(&buyer, &ParticipantRole::Collector, &symbol_short!("b"), &0, &0);
+    StellarAssetClient::new(&env, &token.address).mint(&buyer, &40);
+    client.offer_payment(&buyer, &1, &token.address, &40);
+    assert_eq!(token.balance(&client.address), 90);
+
+    // 60 tokens fit the balance but not the 50 outside escrow.
+    assert_eq!(
+        client.try_redeem_points(&recycler, &600),
+        Err(Ok(Error::InsufficientRewardPool))
+    );
+    assert_eq!(client.redeem_points(&recycler, &500), 50);
+
+    // The escrow is still whole and can be refunded.
+    client.cancel_offer(&1);
+    assert_eq!(token.balance(&buyer), 40);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_redeem_without_token_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.create_material_batch(&recycler, &MaterialKind::Paper, &10_000);
+
+    assert_eq!(
+        client.try_redeem_points(&recycler, &100),
+        Err(Ok(Error::TokenAddressNotSet))
+    );
+}
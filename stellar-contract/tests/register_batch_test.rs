@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_with_admin;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Symbol, Vec,
+};
+use stellar_scavngr_contract::{Error, ParticipantRole, RegisterInput};
+
+fn input(env: &Env, latitude: i128) -> RegisterInput {
+    RegisterInput {
+        address: Address::generate(env),
+        role: ParticipantRole::Recycler,
+        name: symbol_short!("ngo"),
+        latitude,
+        longitude: 0,
+    }
+}
+
+fn inputs(env: &Env, n: u32) -> Vec<RegisterInput> {
+    let mut out = Vec::new(env);
+    for i in 0..n {
+        out.push_back(input(env, i as i128));
+    }
+    out
+}
+
+#[test]
+fn test_register_batch_registers_everyone() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let batch = inputs(&env, 3);
+
+    assert_eq!(client.register_batch(&admin, &batch), 3);
+
+    for entry in batch.iter() {
+        let p = client.get_participant(&entry.address).unwrap();
+        assert_eq!(p.role, ParticipantRole::Recycler);
+        assert_eq!(p.latitude, entry.latitude);
+    }
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 3);
+}
+
+#[test]
+fn test_register_batch_emits_reg_per_entry() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    client.register_batch(&admin, &inputs(&env, 4));
+
+    let reg_events = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let topic: Symbol = topics.get(0).unwrap().into_val(&env);
+            topic == symbol_short!("reg")
+        })
+        .count();
+    assert_eq!(reg_events, 4);
+}
+
+#[test]
+fn test_register_batch_is_atomic() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let mut batch = inputs(&env, 2);
+    batch.push_back(input(&env, 91_000_000));
+
+    assert_eq!(client.try_register_batch(&admin, &batch), Err(Ok(Error::InvalidCoordinates)));
+    for entry in batch.iter() {
+        assert!(client.get_participant(&entry.address).is_none());
+    }
+}
+
+#[test]
+fn test_register_batch_rejects_duplicates() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let mut batch = inputs(&env, 2);
+    batch.push_back(batch.get(0).unwrap());
+
+    assert_eq!(client.try_register_batch(&admin, &batch), Err(Ok(Error::AlreadyRegistered)));
+    assert_eq!(client.count_participants(&ParticipantRole::Recycler), 0);
+}
+
+#[test]
+fn test_register_batch_capped_at_fifty() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(client.register_batch(&admin, &inputs(&env, 50)), 50);
+    assert_eq!(
+        client.try_register_batch(&admin, &inputs(&env, 51)),
+        Err(Ok(Error::TooManyEntries))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_register_batch_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    client.register_batch(&Address::generate(&env), &inputs(&env, 1));
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal, Symbol, Val,
+};
+use stellar_scavngr_contract::{
+    Error, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+#[test]
+fn test_register_participant_twice_returns_already_registered() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+    let events_after_first = env.events().all().len();
+
+    let result = client.try_register_participant(
+        &user,
+        &ParticipantRole::Collector,
+        &symbol_short!("alice2"),
+        &1_000_000,
+        &1_000_000,
+    );
+    assert_eq!(result, Err(Ok(Error::AlreadyRegistered)));
+
+    // Original record untouched, no second `reg` event
+    let stored = client.get_participant(&user).unwrap();
+    assert_eq!(stored.role, ParticipantRole::Recycler);
+    assert_eq!(stored.name, symbol_short!("alice"));
+    assert_eq!(env.events().all().len(), events_after_first);
+}
+
+#[test]
+fn test_register_participant_after_deregistration_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+    client.deregister_participant(&user);
+
+    let result =
+        client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+    assert_eq!(result, Err(Ok(Error::AlreadyRegistered)));
+}
+
+#[test]
+#[should_panic]
+fn test_register_participant_requires_auth() {
+    let env = Env::default();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+}
+
+#[test]
+fn test_register_participant_accepts_coordinate_boundaries() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for (lat, lon) in [
+        (90_000_000i128, 180_000_000i128),
+        (-90_000_000, -180_000_000),
+        (90_000_000, -180_000_000),
+        (-90_000_000, 180_000_000),
+    ] {
+        let user = Address::generate(&env);
+        let p = client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("edge"), &lat, &lon);
+        assert_eq!((p.latitude, p.longitude), (lat, lon));
+    }
+}
+
+#[test]
+fn test_register_participant_rejects_coordinates_just_outside_bounds() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for (lat, lon) in [
+        (90_000_001i128, 0i128),
+        (-90_000_001, 0),
+        (0, 180_000_001),
+        (0, -180_000_001),
+    ] {
+        let user = Address::generate(&env);
+        let result =
+            client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("edge"), &lat, &lon);
+        assert_eq!(result, Err(Ok(Error::InvalidCoordinates)));
+        assert!(client.get_participant(&user).is_none());
+    }
+}
+
+#[test]
+fn test_register_event_is_versioned_and_carries_timestamp() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("bob"), &52_520_000, &13_405_000);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<Val> = (symbol_short!("reg"), user.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+
+    let (version, role, name, lat, lon, registered_at): (u32, u32, Symbol, i128, i128, u64) =
+        data.into_val(&env);
+    assert_eq!(version, 1);
+    assert_eq!(role, ParticipantRole::Collector as u32);
+    assert_eq!(name, symbol_short!("bob"));
+    assert_eq!(lat, 52_520_000);
+    assert_eq!(lon, 13_405_000);
+    assert_eq!(registered_at, 1_700_000_000);
+}
+
+#[test]
+fn test_is_registered_tracks_key_presence() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    assert!(!client.is_registered(&user));
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+    assert!(client.is_registered(&user));
+
+    client.deactivate_participant(&user);
+    assert!(client.is_registered(&user));
+}
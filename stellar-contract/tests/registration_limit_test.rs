@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_with_admin;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContractClient};
+
+fn try_register(client: &ScavengerContractClient, env: &Env) -> Result<(), Error> {
+    let user = Address::generate(env);
+    match client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("u"), &0, &0) {
+        Ok(_) => Ok(()),
+        Err(Ok(e)) => Err(e),
+        Err(Err(e)) => panic!("unexpected invoke error {:?}", e),
+    }
+}
+
+#[test]
+fn test_registrations_beyond_limit_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    assert_eq!(client.get_registration_limit(), 200);
+
+    client.set_registration_limit(&admin, &3);
+    for _ in 0..3 {
+        assert_eq!(try_register(&client, &env), Ok(()));
+    }
+    assert_eq!(try_register(&client, &env), Err(Error::RateLimited));
+}
+
+#[test]
+fn test_limit_resets_next_ledger() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_registration_limit(&admin, &1);
+
+    assert_eq!(try_register(&client, &env), Ok(()));
+    assert_eq!(try_register(&client, &env), Err(Error::RateLimited));
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    assert_eq!(try_register(&client, &env), Ok(()));
+    assert_eq!(try_register(&client, &env), Err(Error::RateLimited));
+}
+
+#[test]
+fn test_rejected_registrations_do_not_count() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_registration_limit(&admin, &1);
+    let user = Address::generate(&env);
+
+    let invalid = client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("u"), &91_000_000, &0);
+    assert_eq!(invalid, Err(Ok(Error::InvalidCoordinates)));
+    assert_eq!(try_register(&client, &env), Ok(()));
+}
+
+#[test]
+fn test_set_registration_limit_rejects_zero() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(client.try_set_registration_limit(&admin, &0), Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_registration_limit_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    client.set_registration_limit(&Address::generate(&env), &10);
+}
@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{register, setup_with_admin};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_remove_participant_deletes_record_and_indexes() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let bad = register(&client, &env, ParticipantRole::Collector);
+    let good = register(&client, &env, ParticipantRole::Collector);
+
+    client.remove_participant(&admin, &bad);
+
+    assert!(client.get_participant(&bad).is_none());
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 1);
+    assert_eq!(
+        client.list_participants(&ParticipantRole::Collector, &0, &10).get(0).unwrap().address,
+        good
+    );
+    let all = client.get_all_participants(&0, &10);
+    assert_eq!(all.len(), 1);
+    assert!(!all.contains(&bad));
+}
+
+#[test]
+fn test_remove_participant_emits_event() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let bad = register(&client, &env, ParticipantRole::Recycler);
+
+    client.remove_participant(&admin, &bad);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("removed"), bad.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let by: Address = data.into_val(&env);
+    assert_eq!(by, admin);
+}
+
+#[test]
+fn test_remove_participant_reassigns_batches_to_admin() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let bad = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let kept = client.create_material_batch(&bad, &MaterialKind::Plastic, &1_000);
+    let handed_on = client.create_material_batch(&bad, &MaterialKind::Metal, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&handed_on, &collector);
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.remove_participant(&admin, &bad);
+
+    let batch = client.get_material_batch(&kept).unwrap();
+    assert_eq!(batch.owner, admin);
+    let chain = client.get_custody_chain(&kept);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.get(0).unwrap().holder, bad);
+    assert_eq!(chain.get(1).unwrap().holder, admin);
+    assert_eq!(chain.get(1).unwrap().received_at, 300);
+
+    // Batches already passed on are untouched.
+    assert_eq!(client.get_material_batch(&handed_on).unwrap().owner, collector);
+    assert_eq!(client.get_custody_chain(&handed_on).len(), 2);
+}
+
+#[test]
+fn test_remove_participant_not_blocked_by_same_ledger_transfer() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let accomplice = register(&client, &env, ParticipantRole::Recycler);
+    let bad = register(&client, &env, ParticipantRole::Collector);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let id = client.create_material_batch(&accomplice, &MaterialKind::Glass, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&id, &bad);
+
+    // Removal in the same ledger as the hand-over still succeeds.
+    client.remove_participant(&admin, &bad);
+
+    assert!(client.get_participant(&bad).is_none());
+    assert_eq!(client.get_material_batch(&id).unwrap().owner, admin);
+    let chain = client.get_custody_chain(&id);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(2).unwrap().holder, admin);
+    assert_eq!(chain.get(2).unwrap().received_at, 200);
+}
+
+#[test]
+fn test_remove_unknown_participant_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    assert_eq!(
+        client.try_remove_participant(&admin, &Address::generate(&env)),
+        Err(Ok(Error::NotRegistered))
+    );
+}
+
+#[test]
+fn test_remove_participant_twice_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let bad = register(&client, &env, ParticipantRole::Manufacturer);
+
+    client.remove_participant(&admin, &bad);
+    assert_eq!(
+        client.try_remove_participant(&admin, &bad),
+        Err(Ok(Error::NotRegistered))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_remove_participant_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let bad = register(&client, &env, ParticipantRole::Recycler);
+    let other = register(&client, &env, ParticipantRole::Recycler);
+
+    client.remove_participant(&other, &bad);
+}
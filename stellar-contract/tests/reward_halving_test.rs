@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, RewardSchedule, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    (client, admin, recycler)
+}
+
+fn at_ledger(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+#[test]
+fn test_rate_is_one_without_schedule() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    assert_eq!(client.current_reward_rate(), 1);
+    assert_eq!(client.get_reward_schedule(), None);
+    client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    assert_eq!(client.get_points(&recycler), 10);
+}
+
+#[test]
+fn test_rate_halves_each_interval_and_floors_at_one() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    client.set_reward_schedule(&admin, &8, &100);
+    assert_eq!(
+        client.get_reward_schedule(),
+        Some(RewardSchedule { base_rate: 8, genesis_ledger: 1_000, halving_interval_ledgers: 100 })
+    );
+
+    for (sequence, rate) in [
+        (1_000, 8),
+        (1_099, 8),
+        (1_100, 4),
+        (1_250, 2),
+        (1_300, 1),
+        (1_400, 1),
+        (2_000, 1),
+    ] {
+        at_ledger(&env, sequence);
+        assert_eq!(client.current_reward_rate(), rate, "ledger {sequence}");
+    }
+}
+
+#[test]
+fn test_batch_points_follow_halving() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+    client.set_reward_schedule(&admin, &4, &50);
+
+    // 1 kg is 10 points at rate 1
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    assert_eq!(client.get_points(&recycler), 40);
+
+    at_ledger(&env, 1_050);
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    assert_eq!(client.get_points(&recycler), 60);
+
+    at_ledger(&env, 1_100);
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    assert_eq!(client.get_points(&recycler), 70);
+
+    at_ledger(&env, 1_500);
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    assert_eq!(client.get_points(&recycler), 80);
+}
+
+#[test]
+fn test_reset_restarts_from_current_ledger() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    client.set_reward_schedule(&admin, &16, &10);
+
+    at_ledger(&env, 1_030);
+    assert_eq!(client.current_reward_rate(), 2);
+
+    client.set_reward_schedule(&admin, &16, &10);
+    assert_eq!(client.current_reward_rate(), 16);
+}
+
+#[test]
+fn test_set_reward_schedule_rejects_zero() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    assert_eq!(client.try_set_reward_schedule(&admin, &0, &10), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_set_reward_schedule(&admin, &8, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.get_reward_schedule(), None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_reward_schedule_requires_admin() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.set_reward_schedule(&recycler, &8, &10);
+}
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    (client, recycler)
+}
+
+#[test]
+fn test_points_start_at_zero() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    assert_eq!(client.get_points(&recycler), 0);
+    assert_eq!(client.get_points_divisor(), 100);
+}
+
+#[test]
+fn test_create_batch_awards_points_by_weight() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.create_material_batch(&recycler, &MaterialKind::Plastic, &2_550);
+    assert_eq!(client.get_points(&recycler), 25);
+
+    client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+    assert_eq!(client.get_points(&recycler), 35);
+}
+
+#[test]
+fn test_award_emits_reward_event() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.create_material_batch(&recycler, &MaterialKind::Metal, &500);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("reward"), recycler.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let amount: u64 = data.into_val(&env);
+    assert_eq!(amount, 5);
+}
+
+#[test]
+fn test_batch_below_divisor_awards_nothing() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.create_material_batch(&recycler, &MaterialKind::Paper, &99);
+    assert_eq!(client.get_points(&recycler), 0);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("mat_new"), 1u64).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_divisor_is_tunable_by_admin() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    client.set_points_divisor(&admin, &10);
+    assert_eq!(client.get_points_divisor(), 10);
+
+    client.create_material_batch(&recycler, &MaterialKind::Plastic, &250);
+    assert_eq!(client.get_points(&recycler), 25);
+}
+
+#[test]
+fn test_zero_divisor_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    assert_eq!(
+        client.try_set_points_divisor(&admin, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(client.get_points_divisor(), 100);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_divisor_requires_admin() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    client.set_points_divisor(&recycler, &10);
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{advance, register, setup_with_admin};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_any_recipient_when_flow_check_off() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let other_recycler = register(&client, &env, ParticipantRole::Recycler);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+
+    assert!(!client.enforce_role_flow());
+    advance(&env);
+    client.transfer_material(&id, &manufacturer);
+    advance(&env);
+    client.transfer_material(&id, &other_recycler);
+}
+
+#[test]
+fn test_canonical_flow_is_allowed() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_role_flow(&admin, &true);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let other_collector = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+
+    for to in [&collector, &other_collector, &manufacturer] {
+        advance(&env);
+        assert_eq!(client.transfer_material(&id, to).owner, *to);
+    }
+}
+
+#[test]
+fn test_backward_and_skipping_transfers_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_role_flow(&admin, &true);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let other_recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    let other_manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+
+    advance(&env);
+    assert_eq!(client.try_transfer_material(&id, &manufacturer), Err(Ok(Error::IllegalFlow)));
+    assert_eq!(client.try_transfer_material(&id, &other_recycler), Err(Ok(Error::IllegalFlow)));
+
+    client.transfer_material(&id, &collector);
+    advance(&env);
+    assert_eq!(client.try_transfer_material(&id, &recycler), Err(Ok(Error::IllegalFlow)));
+
+    client.transfer_material(&id, &manufacturer);
+    advance(&env);
+    assert_eq!(client.try_transfer_material(&id, &collector), Err(Ok(Error::IllegalFlow)));
+    assert_eq!(
+        client.try_transfer_material(&id, &other_manufacturer),
+        Err(Ok(Error::IllegalFlow))
+    );
+}
+
+#[test]
+fn test_multi_role_recipient_uses_any_permitted_role() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_role_flow(&admin, &true);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let hybrid = register(&client, &env, ParticipantRole::Manufacturer);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &1_000);
+
+    advance(&env);
+    assert_eq!(client.try_transfer_material(&id, &hybrid), Err(Ok(Error::IllegalFlow)));
+
+    client.add_role(&hybrid, &ParticipantRole::Collector);
+    assert_eq!(client.transfer_material(&id, &hybrid).owner, hybrid);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_enforce_role_flow_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    client.set_enforce_role_flow(&Address::generate(&env), &true);
+}
@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let user = Address::generate(env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    (client, admin, user)
+}
+
+fn assert_last_event(env: &Env, topic: soroban_sdk::Symbol, address: &Address, role: ParticipantRole) {
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> = (topic, address.clone()).into_val(env);
+    assert_eq!(topics, expected);
+    let emitted: ParticipantRole = data.into_val(env);
+    assert_eq!(emitted, role);
+}
+
+#[test]
+fn test_request_role_is_stored_and_emitted() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    client.request_role(&user, &ParticipantRole::Manufacturer);
+
+    assert_eq!(client.get_pending_role(&user), Some(ParticipantRole::Manufacturer));
+    assert_last_event(&env, symbol_short!("role_req"), &user, ParticipantRole::Manufacturer);
+    // Nothing changes until approval.
+    assert_eq!(client.get_participant(&user).unwrap().role, ParticipantRole::Collector);
+}
+
+#[test]
+fn test_new_request_overwrites_pending() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    client.request_role(&user, &ParticipantRole::Manufacturer);
+    client.request_role(&user, &ParticipantRole::Recycler);
+
+    assert_eq!(client.get_pending_role(&user), Some(ParticipantRole::Recycler));
+}
+
+#[test]
+fn test_approve_role_applies_request() {
+    let env = Env::default();
+    let (client, admin, user) = setup(&env);
+    client.request_role(&user, &ParticipantRole::Manufacturer);
+
+    let updated = client.approve_role(&admin, &user);
+
+    assert_eq!(updated.role, ParticipantRole::Manufacturer);
+    assert!(updated.has_role(ParticipantRole::Manufacturer));
+    assert!(!updated.has_role(ParticipantRole::Collector));
+    assert_eq!(client.get_pending_role(&user), None);
+    assert_eq!(client.count_participants(&ParticipantRole::Manufacturer), 1);
+    assert_eq!(client.count_participants(&ParticipantRole::Collector), 0);
+    assert_last_event(&env, symbol_short!("role_ok"), &user, ParticipantRole::Manufacturer);
+}
+
+#[test]
+fn test_reject_role_discards_request() {
+    let env = Env::default();
+    let (client, admin, user) = setup(&env);
+    client.request_role(&user, &ParticipantRole::Manufacturer);
+
+    client.reject_role(&admin, &user);
+
+    assert_eq!(client.get_pending_role(&user), None);
+    assert_eq!(client.get_participant(&user).unwrap().role, ParticipantRole::Collector);
+    assert_last_event(&env, symbol_short!("role_no"), &user, ParticipantRole::Manufacturer);
+}
+
+#[test]
+fn test_resolving_without_request_fails() {
+    let env = Env::default();
+    let (client, admin, user) = setup(&env);
+
+    assert_eq!(client.try_approve_role(&admin, &user), Err(Ok(Error::NoPendingRole)));
+    assert_eq!(client.try_reject_role(&admin, &user), Err(Ok(Error::NoPendingRole)));
+}
+
+#[test]
+fn test_request_role_requires_registration() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_request_role(&stranger, &ParticipantRole::Manufacturer),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_approve_role_requires_admin() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+    client.request_role(&user, &ParticipantRole::Manufacturer);
+
+    client.approve_role(&user, &user);
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Vec,
+};
+use stellar_scavngr_contract::{
+    BatchStatus, Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+/// Returns a client, a collector owning batch 1 (3 000 g of metal) and the
+/// recycler that created it.
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.create_material_batch(&recycler, &MaterialKind::Metal, &3_000);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.transfer_material(&1, &collector);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+
+    (client, collector, recycler)
+}
+
+#[test]
+fn test_split_batch_creates_children_and_consumes_parent() {
+    let env = Env::default();
+    let (client, collector, _) = setup(&env);
+
+    let ids = client.split_batch(&1, &vec![&env, 1_000u64, 2_000u64]);
+    assert_eq!(ids, vec![&env, 2u64, 3u64]);
+
+    assert!(client.get_material_batch(&1).is_none());
+    let a = client.get_material_batch(&2).unwrap();
+    let b = client.get_material_batch(&3).unwrap();
+    assert_eq!(a.weight_grams, 1_000);
+    assert_eq!(b.weight_grams, 2_000);
+    for child in [a, b] {
+        assert_eq!(child.owner, collector);
+        assert_eq!(child.kind, MaterialKind::Metal);
+        assert_eq!(child.status, BatchStatus::Collected);
+        assert_eq!(child.created_at, 300);
+    }
+}
+
+#[test]
+fn test_split_children_copy_custody_chain() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup(&env);
+
+    client.split_batch(&1, &vec![&env, 1_500u64, 1_500u64]);
+
+    for id in [2u64, 3u64] {
+        let chain = client.get_custody_chain(&id);
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain.get(0).unwrap().holder, recycler);
+        assert_eq!(chain.get(1).unwrap().holder, collector);
+        assert_eq!(chain.get(2).unwrap().holder, collector);
+        assert_eq!(chain.get(2).unwrap().received_at, 300);
+    }
+    // The parent's own history stays readable.
+    assert_eq!(client.get_custody_chain(&1).len(), 2);
+}
+
+#[test]
+fn test_split_emits_event() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.split_batch(&1, &vec![&env, 1_000u64, 2_000u64]);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("split"), 1u64).into_val(&env);
+    assert_eq!(topics, expected);
+    let children: Vec<u64> = data.into_val(&env);
+    assert_eq!(children, vec![&env, 2u64, 3u64]);
+}
+
+#[test]
+fn test_split_children_are_transferable_by_owner() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("m"), &0, &0);
+
+    client.split_batch(&1, &vec![&env, 1_000u64, 2_000u64]);
+    env.ledger().with_mut(|li| li.timestamp = 400);
+    assert_eq!(client.transfer_material(&3, &manufacturer).owner, manufacturer);
+    assert_eq!(
+        client.try_transfer_material(&1, &manufacturer),
+        Err(Ok(Error::BatchNotFound))
+    );
+}
+
+#[test]
+fn test_split_weight_mismatch_rejected() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(
+        client.try_split_batch(&1, &vec![&env, 1_000u64, 1_000u64]),
+        Err(Ok(Error::WeightMismatch))
+    );
+    assert_eq!(
+        client.try_split_batch(&1, &vec![&env, u64::MAX, 3_001u64]),
+        Err(Ok(Error::WeightMismatch))
+    );
+    assert!(client.get_material_batch(&1).is_some());
+}
+
+#[test]
+fn test_split_rejects_zero_and_single_weights() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(
+        client.try_split_batch(&1, &vec![&env, 3_000u64, 0u64]),
+        Err(Ok(Error::InvalidWeight))
+    );
+    assert_eq!(
+        client.try_split_batch(&1, &vec![&env, 3_000u64]),
+        Err(Ok(Error::InvalidWeight))
+    );
+}
+
+#[test]
+fn test_split_recycled_batch_rejected() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.set_batch_status(&1, &BatchStatus::Recycled);
+    assert_eq!(
+        client.try_split_batch(&1, &vec![&env, 1_000u64, 2_000u64]),
+        Err(Ok(Error::BatchRecycled))
+    );
+}
+
+#[test]
+fn test_split_unknown_batch_rejected() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(
+        client.try_split_batch(&42, &vec![&env, 1u64, 1u64]),
+        Err(Ok(Error::BatchNotFound))
+    );
+}
+
+#[test]
+fn test_split_in_same_ledger_as_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &2_000);
+    let ids = client.split_batch(&id, &vec![&env, 500u64, 1_500u64]);
+
+    for child in ids.iter() {
+        let chain = client.get_custody_chain(&child);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain.get(1).unwrap().holder, recycler);
+        assert_eq!(chain.get(1).unwrap().received_at, 100);
+    }
+}
+
+#[test]
+fn test_split_child_can_be_handed_on_in_same_ledger() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("m"), &0, &0);
+
+    let ids = client.split_batch(&1, &vec![&env, 1_000u64, 2_000u64]);
+    let child = ids.get(0).unwrap();
+    client.transfer_material(&child, &manufacturer);
+    assert_eq!(client.get_material_batch(&child).unwrap().owner, manufacturer);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{MaterialKind, ParticipantRole, ScavengerContractClient};
+
+fn recycler(client: &ScavengerContractClient, env: &Env) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    address
+}
+
+#[test]
+fn test_top_recyclers_empty() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.top_recyclers(&5).len(), 0);
+}
+
+#[test]
+fn test_top_recyclers_sorted_by_total_weight() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = recycler(&client, &env);
+    let b = recycler(&client, &env);
+    let c = recycler(&client, &env);
+
+    client.create_material_batch(&a, &MaterialKind::Glass, &300);
+    client.create_material_batch(&b, &MaterialKind::Glass, &500);
+    client.create_material_batch(&c, &MaterialKind::Glass, &100);
+    // A second batch lifts `c` from last to first.
+    client.create_material_batch(&c, &MaterialKind::Metal, &1_000);
+
+    let top = client.top_recyclers(&3);
+    assert_eq!(top.get(0).unwrap(), (c, 1_100));
+    assert_eq!(top.get(1).unwrap(), (b.clone(), 500));
+    assert_eq!(top.get(2).unwrap(), (a, 300));
+
+    let top1 = client.top_recyclers(&1);
+    assert_eq!(top1.len(), 1);
+}
+
+#[test]
+fn test_top_recyclers_capped_at_twenty() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let client = setup(&env);
+
+    for w in 1..=25u64 {
+        let r = recycler(&client, &env);
+        client.create_material_batch(&r, &MaterialKind::Paper, &w);
+    }
+
+    let top = client.top_recyclers(&100);
+    assert_eq!(top.len(), 20);
+    assert_eq!(top.get(0).unwrap().1, 25);
+    assert_eq!(top.get(19).unwrap().1, 6);
+}
+
+#[test]
+fn test_removed_participant_leaves_leaderboard() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let a = recycler(&client, &env);
+    let b = recycler(&client, &env);
+    client.create_material_batch(&a, &MaterialKind::Glass, &300);
+    client.create_material_batch(&b, &MaterialKind::Glass, &500);
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &b);
+
+    let top = client.top_recyclers(&5);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top.get(0).unwrap().0, a);
+}
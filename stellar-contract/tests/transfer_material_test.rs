@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{advance, register, setup};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal,
+};
+use stellar_scavngr_contract::{Error, MaterialKind, ParticipantRole};
+
+#[test]
+fn test_transfer_material_updates_owner_and_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+
+    advance(&env);
+    let batch = client.transfer_material(&id, &collector);
+    assert_eq!(batch.owner, collector);
+    assert_eq!(client.get_material_batch(&id).unwrap().owner, collector);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("mat_xfer"), id).into_val(&env);
+    assert_eq!(topics, expected);
+    let (version, from, to, kind, weight): (u32, Address, Address, MaterialKind, u64) =
+        data.into_val(&env);
+    assert_eq!(version, 1);
+    assert_eq!(from, recycler);
+    assert_eq!(to, collector);
+    assert_eq!(kind, MaterialKind::Plastic);
+    assert_eq!(weight, 1_000);
+}
+
+#[test]
+fn test_transfer_material_unknown_batch() {
+    let env = Env::default();
+    let client = setup(&env);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+
+    assert_eq!(
+        client.try_transfer_material(&7, &collector),
+        Err(Ok(Error::BatchNotFound))
+    );
+}
+
+#[test]
+fn test_transfer_material_to_self_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Glass, &1_000);
+
+    assert_eq!(
+        client.try_transfer_material(&id, &recycler),
+        Err(Ok(Error::SameAddress))
+    );
+}
+
+#[test]
+fn test_transfer_material_to_unregistered_or_inactive_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_transfer_material(&id, &stranger),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+
+    client.deactivate_participant(&collector);
+    assert_eq!(
+        client.try_transfer_material(&id, &collector),
+        Err(Ok(Error::Inactive))
+    );
+}
+
+#[test]
+fn test_transfer_material_from_inactive_owner_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Metal, &1_000);
+    advance(&env);
+
+    client.deactivate_participant(&recycler);
+    assert_eq!(
+        client.try_transfer_material(&id, &collector),
+        Err(Ok(Error::Inactive))
+    );
+    assert_eq!(client.get_material_batch(&id).unwrap().owner, recycler);
+
+    client.reactivate_participant(&recycler);
+    assert_eq!(client.transfer_material(&id, &collector).owner, collector);
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_material_requires_owner_auth() {
+    let env = Env::default();
+    let client = setup(&env);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let id = client.create_material_batch(&recycler, &MaterialKind::Paper, &1_000);
+
+    advance(&env);
+    env.set_auths(&[]);
+    client.transfer_material(&id, &collector);
+}
+
+#[test]
+fn test_transfer_material_role_flow_is_opt_in() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    let recycler = register(&client, &env, ParticipantRole::Recycler);
+    let collector = register(&client, &env, ParticipantRole::Collector);
+    let other_recycler = register(&client, &env, ParticipantRole::Recycler);
+
+    // Collector → Recycler is allowed while the check is off.
+    let id = client.create_material_batch(&recycler, &MaterialKind::Plastic, &1_000);
+    client.transfer_material(&id, &collector);
+    client.transfer_material(&id, &other_recycler);
+
+    client.set_enforce_role_flow(&admin, &true);
+    let manufacturer = register(&client, &env, ParticipantRole::Manufacturer);
+    assert_eq!(
+        client.try_transfer_material(&id, &manufacturer),
+        Err(Ok(Error::IllegalFlow))
+    );
+    assert_eq!(client.transfer_material(&id, &collector).owner, collector);
+    assert_eq!(client.transfer_material(&id, &manufacturer).owner, manufacturer);
+}
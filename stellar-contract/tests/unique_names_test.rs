@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_with_admin;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContractClient};
+
+fn register(client: &ScavengerContractClient, env: &Env, name: Symbol) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Recycler, &name, &0, &0);
+    address
+}
+
+#[test]
+fn test_resolve_name_finds_holder() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    let alice = register(&client, &env, symbol_short!("alice"));
+
+    assert_eq!(client.resolve_name(&symbol_short!("alice")), Some(alice));
+    assert_eq!(client.resolve_name(&symbol_short!("bob")), None);
+}
+
+#[test]
+fn test_duplicate_names_allowed_by_default() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    let first = register(&client, &env, symbol_short!("alice"));
+    register(&client, &env, symbol_short!("alice"));
+
+    assert!(!client.enforce_unique_names());
+    assert_eq!(client.resolve_name(&symbol_short!("alice")), Some(first));
+}
+
+#[test]
+fn test_enforced_register_rejects_taken_name() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_unique_names(&admin, &true);
+    register(&client, &env, symbol_short!("alice"));
+
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_register_participant(&other, &ParticipantRole::Collector, &symbol_short!("alice"), &0, &0),
+        Err(Ok(Error::NameTaken))
+    );
+    assert!(client.get_participant(&other).is_none());
+}
+
+#[test]
+fn test_enforced_update_name_rejects_taken_name() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_unique_names(&admin, &true);
+    register(&client, &env, symbol_short!("alice"));
+    let bob = register(&client, &env, symbol_short!("bob"));
+
+    assert_eq!(client.try_update_name(&bob, &symbol_short!("alice")), Err(Ok(Error::NameTaken)));
+    assert_eq!(client.get_participant(&bob).unwrap().name, symbol_short!("bob"));
+
+    // Renaming to one's own name is a no-op, not a conflict
+    client.update_name(&bob, &symbol_short!("bob"));
+}
+
+#[test]
+fn test_rename_frees_old_name() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_unique_names(&admin, &true);
+    let alice = register(&client, &env, symbol_short!("alice"));
+
+    client.update_name(&alice, &symbol_short!("alicia"));
+    assert_eq!(client.resolve_name(&symbol_short!("alice")), None);
+    assert_eq!(client.resolve_name(&symbol_short!("alicia")), Some(alice));
+
+    let newcomer = register(&client, &env, symbol_short!("alice"));
+    assert_eq!(client.resolve_name(&symbol_short!("alice")), Some(newcomer));
+}
+
+#[test]
+fn test_remove_participant_frees_name() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    client.set_enforce_unique_names(&admin, &true);
+    let alice = register(&client, &env, symbol_short!("alice"));
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.remove_participant(&admin, &alice);
+
+    assert_eq!(client.resolve_name(&symbol_short!("alice")), None);
+    register(&client, &env, symbol_short!("alice"));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_enforce_unique_names_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+
+    client.set_enforce_unique_names(&Address::generate(&env), &true);
+}
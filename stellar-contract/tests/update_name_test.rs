@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Symbol,
+};
+use stellar_scavngr_contract::{
+    Error, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+#[test]
+fn test_update_name_persists_and_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("old"), &0, &0);
+
+    let updated = client.update_name(&user, &symbol_short!("new"));
+    assert_eq!(updated.name, symbol_short!("new"));
+    assert_eq!(client.get_participant(&user).unwrap().name, symbol_short!("new"));
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("name_upd"), user.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let name: Symbol = data.into_val(&env);
+    assert_eq!(name, symbol_short!("new"));
+}
+
+#[test]
+fn test_update_name_accepts_max_length() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    let long = Symbol::new(&env, "abcdefghijklmnopqrstuvwxyz_01234");
+    assert_eq!(client.update_name(&user, &long).name, long);
+}
+
+#[test]
+fn test_update_name_rejects_empty() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+
+    assert_eq!(
+        client.try_update_name(&user, &Symbol::new(&env, "")),
+        Err(Ok(Error::InvalidName))
+    );
+    assert_eq!(client.get_participant(&user).unwrap().name, symbol_short!("c"));
+}
+
+#[test]
+fn test_update_name_unknown_address_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_update_name(&stranger, &symbol_short!("x")),
+        Err(Ok(Error::ParticipantNotFound))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_update_name_requires_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+
+    env.set_auths(&[]);
+    client.update_name(&user, &symbol_short!("new"));
+}
+
+#[test]
+fn test_register_participant_rejects_empty_name() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_register_participant(
+            &user,
+            &ParticipantRole::Recycler,
+            &Symbol::new(&env, ""),
+            &0,
+            &0
+        ),
+        Err(Ok(Error::InvalidName))
+    );
+    assert!(client.get_participant(&user).is_none());
+}
@@ -1,5 +1,5 @@
-use soroban_sdk::{symbol_short, testutils::{Address as _, Events}, Address, Env, IntoVal, TryIntoVal};
-use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+use soroban_sdk::{symbol_short, testutils::{Address as _, Events, Ledger}, Address, Env, IntoVal, TryIntoVal};
+use stellar_scavngr_contract::{Error, ParticipantRole, ScavengerContract, ScavengerContractClient};
 
 fn setup(env: &Env) -> (ScavengerContractClient, Address) {
     let contract_id = env.register_contract(None, ScavengerContract);
@@ -53,9 +53,21 @@ fn test_update_participant_location_emits_event() {
         (symbol_short!("loc_upd"), participant.clone()).into_val(&env);
     assert_eq!(event.1, expected_topics);
 
-    let data: (i128, i128) = event.2.try_into_val(&env).unwrap();
-    assert_eq!(data.0, 35_000_000);
-    assert_eq!(data.1, 139_000_000);
+    let data: (i128, i128, bool) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (35_000_000, 139_000_000, false));
+}
+
+#[test]
+fn test_update_participant_location_preserves_registered_at() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, participant) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let updated = client.update_participant_location(&participant, &1_000_000, &2_000_000);
+
+    assert_eq!(updated.registered_at, 1_000);
+    assert_eq!(client.get_participant(&participant).unwrap().registered_at, 1_000);
 }
 
 #[test]
@@ -77,41 +89,47 @@ fn test_update_participant_location_boundary_values() {
 }
 
 #[test]
-#[should_panic(expected = "Latitude must be between -90 and +90 degrees")]
 fn test_update_participant_location_invalid_latitude() {
     let env = Env::default();
     let (client, participant) = setup(&env);
-    client.update_participant_location(&participant, &91_000_000, &0);
+    assert_eq!(
+        client.try_update_participant_location(&participant, &91_000_000, &0),
+        Err(Ok(Error::InvalidCoordinates))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Longitude must be between -180 and +180 degrees")]
 fn test_update_participant_location_invalid_longitude() {
     let env = Env::default();
     let (client, participant) = setup(&env);
-    client.update_participant_location(&participant, &0, &181_000_000);
+    assert_eq!(
+        client.try_update_location(&participant, &0, &181_000_000, &None),
+        Err(Ok(Error::InvalidCoordinates))
+    );
 }
 
 #[test]
-#[should_panic]
 fn test_update_participant_location_unregistered() {
     let env = Env::default();
     let contract_id = env.register_contract(None, ScavengerContract);
     let client = ScavengerContractClient::new(&env, &contract_id);
     let stranger = Address::generate(&env);
     env.mock_all_auths();
-    client.update_participant_location(&stranger, &0, &0);
+    assert_eq!(
+        client.try_update_participant_location(&stranger, &0, &0),
+        Err(Ok(Error::ParticipantNotFound))
+    );
 }
 
 #[test]
-fn test_update_location_deprecated_alias_works() {
+fn test_update_location_without_proof_matches_participant_location() {
     let env = Env::default();
     let (client, participant) = setup(&env);
 
-    // update_location is a deprecated alias — must produce the same result
-    let via_alias = client.update_location(&participant, &48_000_000, &2_000_000);
-    let via_new = client.get_participant(&participant).unwrap();
+    let updated = client.update_location(&participant, &48_000_000, &2_000_000, &None);
+    let fetched = client.get_participant(&participant).unwrap();
 
-    assert_eq!(via_alias.latitude, 48_000_000);
-    assert_eq!(via_new.latitude, 48_000_000);
+    assert_eq!(updated.latitude, 48_000_000);
+    assert_eq!(fetched, updated);
+    assert!(!fetched.location_verified);
 }
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_with_admin;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, Bytes, BytesN, Env, IntoVal,
+};
+use stellar_scavngr_contract::{ScavengerContract, ScavengerContractClient};
+
+/// Smallest module the host accepts as contract code: the Wasm header plus a
+/// `contractenvmetav0` section declaring interface version 21.0. It exports
+/// nothing, so it can be installed but not invoked.
+const EMPTY_CONTRACT_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic, version 1
+    0x00, 0x1e, 0x11, // custom section, 30 bytes, 17-byte name
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', //
+    0x00, 0x00, 0x00, 0x00, // SCEnvMetaEntry::InterfaceVersion
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, // protocol 21, pre-release 0
+];
+
+#[test]
+fn test_upgrade_installs_uploaded_wasm() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let hash = env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&env, EMPTY_CONTRACT_WASM));
+
+    client.upgrade(&admin, &hash);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (soroban_sdk::symbol_short!("upgrade"), admin).into_val(&env);
+    assert_eq!(topics, expected);
+    let emitted: BytesN<32> = data.into_val(&env);
+    assert_eq!(emitted, hash);
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_rejects_unknown_hash() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+
+    client.upgrade(&admin, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_upgrade_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let hash = env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&env, EMPTY_CONTRACT_WASM));
+
+    client.upgrade(&Address::generate(&env), &hash);
+}
+
+#[test]
+#[should_panic(expected = "Admin not set")]
+fn test_upgrade_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+
+    client.upgrade(&Address::generate(&env), &BytesN::from_array(&env, &[7; 32]));
+}
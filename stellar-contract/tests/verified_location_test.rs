@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    xdr::ToXdr,
+    Address, BytesN, Env,
+};
+use stellar_scavngr_contract::{
+    Error, LocationProof, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+const NOW: u64 = 1_700_000_000;
+
+struct Fixture<'a> {
+    client: ScavengerContractClient<'a>,
+    oracle: SigningKey,
+    user: Address,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let oracle = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_location_oracle(&admin, &BytesN::from_array(env, &oracle.verifying_key().to_bytes()));
+
+    let user = Address::generate(env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    Fixture { client, oracle, user }
+}
+
+fn prove(env: &Env, key: &SigningKey, user: &Address, lat: i128, lon: i128, ts: u64) -> Option<LocationProof> {
+    let message = (user.clone(), lat, lon, ts).to_xdr(env);
+    let bytes: std::vec::Vec<u8> = message.iter().collect();
+    Some(LocationProof {
+        timestamp: ts,
+        signature: BytesN::from_array(env, &key.sign(&bytes).to_bytes()),
+    })
+}
+
+#[test]
+fn test_signed_update_marks_location_verified() {
+    let env = Env::default();
+    let f = setup(&env);
+    assert!(!f.client.is_location_verified(&f.user));
+
+    let proof = prove(&env, &f.oracle, &f.user, 52_520_000, 13_405_000, NOW - 10);
+    let p = f.client.update_location(&f.user, &52_520_000, &13_405_000, &proof);
+
+    assert_eq!((p.latitude, p.longitude), (52_520_000, 13_405_000));
+    assert!(p.location_verified);
+    assert!(f.client.is_location_verified(&f.user));
+}
+
+#[test]
+fn test_unsigned_update_clears_verification() {
+    let env = Env::default();
+    let f = setup(&env);
+    let proof = prove(&env, &f.oracle, &f.user, 1, 2, NOW);
+    f.client.update_location(&f.user, &1, &2, &proof);
+
+    let p = f.client.update_location(&f.user, &3, &4, &None);
+
+    assert!(!p.location_verified);
+    assert!(!f.client.is_location_verified(&f.user));
+}
+
+#[test]
+#[should_panic]
+fn test_signature_over_other_coordinates_rejected() {
+    let env = Env::default();
+    let f = setup(&env);
+    let proof = prove(&env, &f.oracle, &f.user, 1, 2, NOW);
+
+    f.client.update_location(&f.user, &1, &3, &proof);
+}
+
+#[test]
+#[should_panic]
+fn test_signature_from_other_key_rejected() {
+    let env = Env::default();
+    let f = setup(&env);
+    let impostor = SigningKey::from_bytes(&[9u8; 32]);
+    let proof = prove(&env, &impostor, &f.user, 1, 2, NOW);
+
+    f.client.update_location(&f.user, &1, &2, &proof);
+}
+
+#[test]
+fn test_stale_or_future_proof_rejected() {
+    let env = Env::default();
+    let f = setup(&env);
+
+    let old = NOW - 301;
+    let proof = prove(&env, &f.oracle, &f.user, 1, 2, old);
+    assert_eq!(
+        f.client.try_update_location(&f.user, &1, &2, &proof),
+        Err(Ok(Error::StaleLocationProof))
+    );
+
+    let future = NOW + 1;
+    let proof = prove(&env, &f.oracle, &f.user, 1, 2, future);
+    assert_eq!(
+        f.client.try_update_location(&f.user, &1, &2, &proof),
+        Err(Ok(Error::StaleLocationProof))
+    );
+}
+
+#[test]
+fn test_verified_update_without_oracle_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(&env, &env.register_contract(None, ScavengerContract));
+    let user = Address::generate(&env);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    let proof = Some(LocationProof {
+        timestamp: 0,
+        signature: BytesN::from_array(&env, &[0u8; 64]),
+    });
+
+    assert_eq!(
+        client.try_update_location(&user, &1, &2, &proof),
+        Err(Ok(Error::LocationOracleNotSet))
+    );
+}
+
+#[test]
+fn test_verified_update_validates_coordinates() {
+    let env = Env::default();
+    let f = setup(&env);
+    let proof = prove(&env, &f.oracle, &f.user, 91_000_000, 0, NOW);
+
+    assert_eq!(
+        f.client.try_update_location(&f.user, &91_000_000, &0, &proof),
+        Err(Ok(Error::InvalidCoordinates))
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+use stellar_scavngr_contract::{
+    Error, MaterialKind, ParticipantRole, ScavengerContract, ScavengerContractClient,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = ScavengerContractClient::new(env, &env.register_contract(None, ScavengerContract));
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("r"), &0, &0);
+    (client, recycler)
+}
+
+fn create_at(client: &ScavengerContractClient, env: &Env, recycler: &Address, ts: u64, weight: u64) -> u64 {
+    env.ledger().with_mut(|li| li.timestamp = ts);
+    client.create_material_batch(recycler, &MaterialKind::Plastic, &weight)
+}
+
+#[test]
+fn test_weight_in_window_is_inclusive() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    create_at(&client, &env, &recycler, 100, 1);
+    create_at(&client, &env, &recycler, 200, 20);
+    create_at(&client, &env, &recycler, 300, 300);
+    create_at(&client, &env, &recycler, 400, 4_000);
+
+    assert_eq!(client.weight_in_window(&recycler, &200, &300), 320);
+    assert_eq!(client.weight_in_window(&recycler, &201, &299), 0);
+    assert_eq!(client.weight_in_window(&recycler, &400, &400), 4_000);
+    assert_eq!(client.weight_in_window(&recycler, &0, &u64::MAX), 4_321);
+}
+
+#[test]
+fn test_weight_in_window_counts_only_creator() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("c"), &0, &0);
+    let id = create_at(&client, &env, &recycler, 100, 500);
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    client.transfer_material(&id, &collector);
+
+    assert_eq!(client.weight_in_window(&recycler, &0, &200), 500);
+    assert_eq!(client.weight_in_window(&collector, &0, &200), 0);
+}
+
+#[test]
+fn test_weight_in_window_survives_split_and_merge() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+    let a = create_at(&client, &env, &recycler, 100, 1_000);
+    let b = create_at(&client, &env, &recycler, 110, 500);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.split_batch(&a, &vec![&env, 400, 600]);
+    env.ledger().with_mut(|li| li.timestamp = 600);
+    client.merge_batches(&recycler, &vec![&env, b, 3]);
+
+    assert_eq!(client.weight_in_window(&recycler, &100, &110), 1_500);
+    assert_eq!(client.weight_in_window(&recycler, &500, &600), 0);
+}
+
+#[test]
+fn test_weight_in_window_keeps_most_recent_500() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, recycler) = setup(&env);
+    for ts in 1..=501 {
+        create_at(&client, &env, &recycler, ts, 1);
+    }
+
+    // The batch created at ts 1 has aged out of the history
+    assert_eq!(client.weight_in_window(&recycler, &0, &1_000), 500);
+    assert_eq!(client.weight_in_window(&recycler, &1, &1), 0);
+    assert_eq!(client.get_total_weight(&recycler), 501);
+}
+
+#[test]
+fn test_weight_in_window_rejects_inverted_window() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    assert_eq!(client.try_weight_in_window(&recycler, &10, &9), Err(Ok(Error::InvalidWindow)));
+}